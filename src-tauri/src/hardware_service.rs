@@ -1,45 +1,52 @@
-use serde::Serialize;
-use std::sync::Arc;
-use sysinfo::{CpuExt, System, SystemExt};
-use chrono::Utc;
-
-#[derive(Debug, Serialize)]
-pub struct HardwareInfo {
-    #[serde(rename = "cpuCount")]
-    pub cpu_count: i32,
-    #[serde(rename = "cpuBrand")]
-    pub cpu_brand: String,
-    #[serde(rename = "memoryTotal")]
-    pub memory_total: u64,
-    #[serde(rename = "memoryUsed")]
-    pub memory_used: u64,
-    pub platform: String,
-}
+use crate::hardware::{HardwareError, HardwareInfo, WarmSystem};
+use std::time::{Duration, Instant};
 
+/// Hardware reader that keeps one `System` alive between calls.
+///
+/// Built on the same `WarmSystem` as prewarmed readings, so it only ever loads the
+/// CPU, memory and sensor data readings use, and waits out sysinfo's minimum CPU
+/// update interval between samples.
+///
+/// By default every reading refreshes first. In incremental mode, created with
+/// `with_refresh_interval`, readings are served from the maintained state and only
+/// refresh once it is older than the interval, or when `refresh` is called.
 pub struct HardwareService {
-    sys: Arc<System>,
+    system: WarmSystem,
+    refresh_interval: Option<Duration>,
 }
 
 impl HardwareService {
     pub fn new() -> Self {
-        let mut sys = System::new_all();
-        sys.refresh_all();
+        Self::with_refresh_interval(None)
+    }
+
+    /// Creates a service whose readings only refresh once the maintained state is
+    /// older than `refresh_interval`; `None` refreshes on every reading
+    pub fn with_refresh_interval(refresh_interval: Option<Duration>) -> Self {
         Self {
-            sys: Arc::new(sys),
+            system: WarmSystem::new(),
+            refresh_interval,
         }
     }
 
-    pub fn get_hardware_info(&self) -> HardwareInfo {
-        let mut sys = System::new_all();
-        sys.refresh_all();
-        
-        HardwareInfo {
-            cpu_count: sys.cpus().len() as i32,
-            cpu_brand: sys.cpus()[0].brand().to_string(),
-            memory_total: sys.total_memory(),
-            memory_used: sys.used_memory(),
-            platform: std::env::consts::OS.to_string(),
-        }
+    /// Refreshes the maintained CPU, memory and sensor state now
+    pub fn refresh(&mut self) {
+        self.system.refresh();
+    }
+
+    /// When the maintained state was last refreshed
+    pub fn last_refreshed(&self) -> Instant {
+        self.system.refreshed_at()
+    }
+
+    pub fn get_hardware_info(&self) -> Result<HardwareInfo, HardwareError> {
+        self.system.read_within(self.refresh_interval.unwrap_or(Duration::ZERO))
+    }
+}
+
+impl Default for HardwareService {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -47,27 +54,82 @@ impl HardwareService {
 mod tests {
     use super::*;
     use std::thread;
-    use std::time::Duration;
+    use sysinfo::{System, SystemExt};
 
     #[test]
     fn test_hardware_service() {
         let service = HardwareService::new();
-        let info = service.get_hardware_info();
+        let info = service.get_hardware_info().expect("Failed to read hardware info");
 
         // Basic validation
         assert!(info.cpu_count > 0);
         assert!(!info.cpu_brand.is_empty());
         assert!(info.memory_used <= info.memory_total);
-        assert!(!info.platform.is_empty());
 
         // Test multiple readings
-        let info1 = service.get_hardware_info();
+        let info1 = service.get_hardware_info().unwrap();
         thread::sleep(Duration::from_millis(100));
-        let info2 = service.get_hardware_info();
+        let info2 = service.get_hardware_info().unwrap();
 
         // Values should be consistent
         assert_eq!(info1.cpu_count, info2.cpu_count);
         assert_eq!(info1.cpu_brand, info2.cpu_brand);
         assert_eq!(info1.platform, info2.platform);
     }
+
+    #[test]
+    fn test_repeated_calls_reuse_system() {
+        let service = HardwareService::new();
+        let first = service.get_hardware_info().unwrap();
+
+        for _ in 0..5 {
+            let info = service.get_hardware_info().unwrap();
+            assert_eq!(info.cpu_count, first.cpu_count, "CPU count should stay consistent");
+            assert_eq!(info.cpu_brand, first.cpu_brand, "CPU brand should stay consistent");
+            assert_eq!(info.memory_total, first.memory_total, "Total memory should stay consistent");
+            assert!(info.memory_used <= info.memory_total);
+        }
+
+        service.system.with_system(|sys| {
+            assert!(sys.processes().is_empty(), "Readings should not enumerate processes");
+            assert!(sys.disks().is_empty(), "Readings should not enumerate disks");
+            assert!(sys.users().is_empty(), "Readings should not enumerate users");
+        });
+    }
+
+    #[test]
+    fn test_incremental_mode_reads_maintained_state() {
+        let mut service = HardwareService::with_refresh_interval(Some(Duration::from_secs(3600)));
+        let created = service.last_refreshed();
+
+        let first = service.get_hardware_info().unwrap();
+        thread::sleep(Duration::from_millis(50));
+        let second = service.get_hardware_info().unwrap();
+        assert_eq!(service.last_refreshed(), created, "Fresh state should not be refreshed");
+        assert_eq!(first.memory_used, second.memory_used, "Readings should come from the same state");
+
+        service.refresh();
+        assert!(service.last_refreshed() > created, "Explicit refresh should update the state");
+        let refreshed = service.last_refreshed();
+        service.get_hardware_info().unwrap();
+        assert_eq!(service.last_refreshed(), refreshed, "Reading after a refresh should not refresh again");
+
+        let eager = HardwareService::new();
+        let created = eager.last_refreshed();
+        thread::sleep(Duration::from_millis(10));
+        eager.get_hardware_info().unwrap();
+        assert!(eager.last_refreshed() > created, "Without an interval every reading refreshes");
+    }
+
+    #[test]
+    fn test_back_to_back_readings_take_new_cpu_samples() {
+        let service = HardwareService::new();
+        service.get_hardware_info().unwrap();
+        let first = service.last_refreshed();
+        service.get_hardware_info().unwrap();
+        assert!(
+            service.last_refreshed() - first >= System::MINIMUM_CPU_UPDATE_INTERVAL,
+            "Consecutive CPU samples should be at least the minimum update interval apart"
+        );
+    }
 } 
\ No newline at end of file
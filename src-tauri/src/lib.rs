@@ -1,73 +1,384 @@
+/// Long-lived hardware reader that reuses a single sysinfo `System`
+pub mod hardware_service;
+
 /// Hardware detection and monitoring module
 /// 
 /// This module provides functionality to detect and monitor system hardware capabilities,
 /// including CPU information and memory usage. It's designed to work cross-platform and
 /// provides real-time system resource information.
 pub mod hardware {
-    use sysinfo::{CpuExt, System, SystemExt};
+    use sysinfo::{ComponentExt, CpuExt, DiskExt, CpuRefreshKind, NetworkExt, NetworksExt, PidExt, ProcessExt, RefreshKind, System, SystemExt};
     use serde::{Serialize, Deserialize};
     use std::num::NonZeroU64;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
     use std::thread;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex, OnceLock};
+    #[cfg(debug_assertions)]
+    use std::cell::Cell;
 
     /// Custom error type for hardware-related operations
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, thiserror::Error)]
+    #[cfg_attr(feature = "ts", derive(ts_rs::TS))]
     pub enum HardwareError {
         /// CPU-related errors
+        #[error("CPU Error: {0}")]
         CpuError(String),
         /// Memory-related errors
+        #[error("Memory Error: {0}")]
         MemoryError(String),
         /// System compatibility errors
+        #[error("Compatibility Error: {0}")]
         CompatibilityError(String),
         /// General system errors
+        #[error("System Error: {0}")]
         SystemError(String),
     }
 
-    impl std::fmt::Display for HardwareError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// Operating system the application runs on.
+    ///
+    /// Serialized as the lowercase names used before this enum existed ("windows",
+    /// "macos", "linux", or the raw OS name for anything else), so the JSON contract
+    /// with the frontend is unchanged.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Platform {
+        Windows,
+        MacOs,
+        Linux,
+        Ios,
+        Android,
+        FreeBsd,
+        Other(String),
+    }
+
+    impl Platform {
+        /// Normalizes an OS name such as `std::env::consts::OS`, mapping "darwin" to macOS.
+        ///
+        /// This is the single place OS names are mapped; both hardware detection and
+        /// requirement deserialization go through it.
+        pub fn from_os_str(os: &str) -> Self {
+            let os = os.trim().to_lowercase();
+            match os.as_str() {
+                "windows" => Platform::Windows,
+                "macos" | "darwin" => Platform::MacOs,
+                "linux" => Platform::Linux,
+                "ios" | "iphoneos" => Platform::Ios,
+                "android" => Platform::Android,
+                "freebsd" => Platform::FreeBsd,
+                _ => Platform::Other(os),
+            }
+        }
+
+        /// Returns the platform the application was built for
+        pub fn current() -> Self {
+            Self::from_os_str(std::env::consts::OS)
+        }
+
+        /// Returns the canonical lowercase name
+        pub fn as_str(&self) -> &str {
             match self {
-                HardwareError::CpuError(msg) => write!(f, "CPU Error: {}", msg),
-                HardwareError::MemoryError(msg) => write!(f, "Memory Error: {}", msg),
-                HardwareError::CompatibilityError(msg) => write!(f, "Compatibility Error: {}", msg),
-                HardwareError::SystemError(msg) => write!(f, "System Error: {}", msg),
+                Platform::Windows => "windows",
+                Platform::MacOs => "macos",
+                Platform::Linux => "linux",
+                Platform::Ios => "ios",
+                Platform::Android => "android",
+                Platform::FreeBsd => "freebsd",
+                Platform::Other(os) => os,
             }
         }
     }
 
+    impl std::fmt::Display for Platform {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl Serialize for Platform {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Platform {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let os = String::deserialize(deserializer)?;
+            Ok(Platform::from_os_str(&os))
+        }
+    }
+
     /// System compatibility requirements
     #[derive(Debug, Serialize, Deserialize)]
     pub struct SystemRequirements {
-        min_cpu_cores: usize,
+        pub min_cpu_cores: usize,
+        /// Minimum memory in KiB; private so callers go through the unit-aware
+        /// `min_memory` accessor and builder setters
         min_memory_kb: u64,
-        supported_platforms: Vec<String>,
+        pub supported_platforms: Vec<Platform>,
+    }
+
+    impl SystemRequirements {
+        /// Creates requirements from typed platforms, so a misspelled platform name
+        /// can't silently exclude every machine of that kind
+        pub fn new(min_cpu_cores: usize, min_memory: Kib, supported_platforms: &[Platform]) -> Self {
+            Self {
+                min_cpu_cores,
+                min_memory_kb: min_memory.0,
+                supported_platforms: supported_platforms.to_vec(),
+            }
+        }
+
+        /// Starts a builder from the default requirements
+        pub fn builder() -> SystemRequirementsBuilder {
+            SystemRequirementsBuilder { reqs: Self::default() }
+        }
+
+        /// Returns the minimum memory requirement
+        pub fn min_memory(&self) -> Kib {
+            Kib(self.min_memory_kb)
+        }
+
+        /// Returns true if the platform is one of the supported platforms
+        pub fn supports(&self, platform: &Platform) -> bool {
+            self.supported_platforms.contains(platform)
+        }
     }
 
     impl Default for SystemRequirements {
         fn default() -> Self {
             Self {
                 min_cpu_cores: 2,
-                min_memory_kb: 4 * 1024 * 1024, // 4GB
+                min_memory_kb: Kib::from(Mib(4 * 1024)).0, // 4GB
                 supported_platforms: vec![
-                    "windows".to_string(),
-                    "macos".to_string(),
-                    "linux".to_string(),
+                    Platform::Windows,
+                    Platform::MacOs,
+                    Platform::Linux,
                 ],
             }
         }
     }
 
+    /// Builds `SystemRequirements` with memory given in explicit units
+    #[derive(Debug)]
+    pub struct SystemRequirementsBuilder {
+        reqs: SystemRequirements,
+    }
+
+    impl SystemRequirementsBuilder {
+        pub fn min_cpu_cores(mut self, cores: usize) -> Self {
+            self.reqs.min_cpu_cores = cores;
+            self
+        }
+
+        /// Sets the minimum memory in gibibytes
+        pub fn min_memory_gib(self, gib: u64) -> Self {
+            self.min_memory_mib(gib.saturating_mul(1024))
+        }
+
+        /// Sets the minimum memory in mebibytes
+        pub fn min_memory_mib(self, mib: u64) -> Self {
+            self.min_memory(Kib::from(Mib(mib)))
+        }
+
+        /// Sets the minimum memory in kibibytes
+        pub fn min_memory(mut self, memory: Kib) -> Self {
+            self.reqs.min_memory_kb = memory.0;
+            self
+        }
+
+        pub fn supported_platforms(mut self, platforms: &[Platform]) -> Self {
+            self.reqs.supported_platforms = platforms.to_vec();
+            self
+        }
+
+        pub fn build(self) -> SystemRequirements {
+            self.reqs
+        }
+    }
+
     /// Represents the system hardware information
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    ///
+    /// With the `ts` feature, `u64` fields are exported to TypeScript as `number`
+    /// rather than `bigint`, since serde_json sends them as plain JSON numbers.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    #[cfg_attr(feature = "ts", derive(ts_rs::TS))]
     pub struct HardwareInfo {
         #[serde(rename = "cpuCount")]
         pub cpu_count: usize,
         #[serde(rename = "cpuBrand")]
         pub cpu_brand: String,
+        /// Physical core count, which ML throughput scales with rather than hyperthreads
+        #[serde(rename = "physicalCores")]
+        pub physical_cores: Option<usize>,
+        /// CPU time the process's cgroup may use, in cores (e.g. 1.5), when a quota
+        /// below `cpu_count` is set. Thread pools should be sized by this in containers.
+        #[serde(rename = "availableCpuCores")]
+        pub available_cpu_cores: Option<f64>,
+        /// CPU architecture the application was built for (e.g. "x86_64", "aarch64")
+        pub arch: String,
+        /// CPU temperature in degrees Celsius, when the platform exposes sensors.
+        ///
+        /// Linux usually needs the hwmon drivers set up by `lm-sensors`, and macOS may
+        /// require elevated access, so this is often `None`.
+        #[serde(rename = "cpuTemperatureC")]
+        pub cpu_temperature_c: Option<f32>,
+        /// Global CPU usage across all cores, 0-100%.
+        ///
+        /// sysinfo computes usage as the difference between two samples, which both the
+        /// cold triple refresh and the prewarmed shared system provide. The shared system
+        /// waits out `MINIMUM_CPU_UPDATE_INTERVAL` between samples, so back-to-back
+        /// readings don't report usage over a window too short to be meaningful.
+        #[serde(rename = "cpuUsagePercent")]
+        pub cpu_usage_percent: Option<f32>,
+        /// Current clock of the first core in MHz. Some platforms (notably Apple Silicon
+        /// and many VMs) report 0, which is exposed as `None`.
+        #[serde(rename = "cpuFrequencyMhz")]
+        #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+        pub cpu_frequency_mhz: Option<u64>,
+        /// Maximum rated clock in MHz, from cpufreq on Linux and sysctl on Intel Macs
+        #[serde(rename = "cpuMaxFrequencyMhz")]
+        #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+        pub cpu_max_frequency_mhz: Option<u64>,
+        /// Total physical memory in bytes, as returned by sysinfo's `total_memory()`
         #[serde(rename = "memoryTotal")]
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
         pub memory_total: u64,
+        /// Used physical memory in bytes, as returned by sysinfo's `used_memory()`
         #[serde(rename = "memoryUsed")]
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
         pub memory_used: u64,
-        pub platform: String,
+        /// Memory that can be handed to new allocations in bytes, including reclaimable
+        /// cache, as returned by sysinfo's `available_memory()`
+        #[serde(rename = "availableMemory")]
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        pub available_memory: u64,
+        #[serde(rename = "swapTotal")]
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        pub swap_total: u64,
+        #[serde(rename = "swapUsed")]
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        pub swap_used: u64,
+        /// Memory limit imposed by the process's cgroup, if it is lower than physical memory
+        #[serde(rename = "memoryLimit")]
+        #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+        pub memory_limit: Option<u64>,
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        pub platform: Platform,
+        /// Full OS name and version (e.g. "Linux 22.04 Ubuntu")
+        #[serde(rename = "osVersion")]
+        pub os_version: Option<String>,
+        #[serde(rename = "kernelVersion")]
+        pub kernel_version: Option<String>,
+        /// RFC 3339 time the reading was taken, with nanosecond precision
+        #[serde(rename = "collectedAt")]
+        pub collected_at: String,
+    }
+
+    /// A memory amount in bytes, the unit sysinfo reports
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Bytes(pub u64);
+
+    /// A memory amount in kibibytes (1 KiB = 1024 bytes)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Kib(pub u64);
+
+    /// A memory amount in mebibytes (1 MiB = 1024 KiB)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Mib(pub u64);
+
+    impl Bytes {
+        /// Converts to whole kibibytes, rounding down
+        pub fn to_kib(self) -> Kib {
+            Kib(self.0 / 1024)
+        }
+
+        /// Converts to whole mebibytes, rounding down
+        pub fn to_mib(self) -> Mib {
+            Mib(self.0 / (1024 * 1024))
+        }
+    }
+
+    impl Kib {
+        /// Converts to whole mebibytes, rounding down
+        pub fn to_mib(self) -> Mib {
+            Mib(self.0 / 1024)
+        }
+    }
+
+    // Widening conversions are exact, saturating at u64::MAX instead of overflowing
+    impl From<Kib> for Bytes {
+        fn from(kib: Kib) -> Self {
+            Bytes(kib.0.saturating_mul(1024))
+        }
+    }
+
+    impl From<Mib> for Bytes {
+        fn from(mib: Mib) -> Self {
+            Bytes(mib.0.saturating_mul(1024 * 1024))
+        }
+    }
+
+    impl From<Mib> for Kib {
+        fn from(mib: Mib) -> Self {
+            Kib(mib.0.saturating_mul(1024))
+        }
+    }
+
+    /// Human-readable view of a memory amount.
+    ///
+    /// Holds a byte count. sysinfo 0.26 and later (0.29 is in use) report memory in
+    /// bytes, not kilobytes, so `HardwareInfo` values can be wrapped directly with
+    /// `from_bytes`. Units are binary: 1 KiB = 1024 bytes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct MemoryDisplay(u64);
+
+    impl MemoryDisplay {
+        const KIB: u64 = 1024;
+        const MIB: u64 = 1024 * 1024;
+        const GIB: u64 = 1024 * 1024 * 1024;
+        const TIB: u64 = 1024 * 1024 * 1024 * 1024;
+
+        /// Wraps an amount given in bytes
+        pub fn from_bytes(bytes: u64) -> Self {
+            Self(bytes)
+        }
+
+        /// Wraps an amount given in kibibytes
+        pub fn from_kib(kib: u64) -> Self {
+            Self(Bytes::from(Kib(kib)).0)
+        }
+
+        /// Returns the amount in bytes
+        pub fn bytes(&self) -> u64 {
+            self.0
+        }
+
+        /// Returns the amount in gibibytes
+        pub fn as_gib(&self) -> f64 {
+            self.0 as f64 / Self::GIB as f64
+        }
+
+        /// Formats the amount with the largest unit that keeps the value at or above 1,
+        /// rounded to one decimal place (e.g. "15.6 GiB"). Amounts that round up to 1024
+        /// of a unit are shown in the next one, so 1 GiB minus a few bytes is "1.0 GiB".
+        pub fn format_human(&self) -> String {
+            const UNITS: [(u64, &str); 4] = [
+                (MemoryDisplay::KIB, "KiB"),
+                (MemoryDisplay::MIB, "MiB"),
+                (MemoryDisplay::GIB, "GiB"),
+                (MemoryDisplay::TIB, "TiB"),
+            ];
+            let Some(mut index) = UNITS.iter().rposition(|(size, _)| self.0 >= *size) else {
+                return format!("{} B", self.0);
+            };
+            let rounded = |index: usize| (self.0 as f64 / UNITS[index].0 as f64 * 10.0).round() / 10.0;
+            if rounded(index) >= 1024.0 && index + 1 < UNITS.len() {
+                index += 1;
+            }
+            format!("{:.1} {}", rounded(index), UNITS[index].1)
+        }
     }
 
     impl HardwareInfo {
@@ -89,237 +400,2679 @@ pub mod hardware {
                 return Err(HardwareError::MemoryError("Used memory exceeds total memory".to_string()));
             }
 
+            if self.available_memory > self.memory_total {
+                return Err(HardwareError::MemoryError("Available memory exceeds total memory".to_string()));
+            }
+
+            if self.swap_used > self.swap_total {
+                return Err(HardwareError::MemoryError("Used swap exceeds total swap".to_string()));
+            }
+
             Ok(())
         }
 
+        /// Returns the memory actually available to the process, taking the cgroup limit into account
+        pub fn effective_memory_total(&self) -> u64 {
+            match self.memory_limit {
+                Some(limit) => limit.min(self.memory_total),
+                None => self.memory_total,
+            }
+        }
+
+        /// Classifies how close the system is to running out of memory.
+        ///
+        /// Based on the share of total memory that is still available, which unlike
+        /// `memory_used` accounts for cache the OS can reclaim. Under a cgroup memory
+        /// limit both are measured against the limit rather than host RAM.
+        pub fn memory_pressure(&self) -> MemoryPressure {
+            let total = self.effective_memory_total();
+            if total == 0 {
+                return MemoryPressure::High;
+            }
+            let available_ratio = self.available_memory.min(total) as f64 / total as f64;
+            if available_ratio >= MEMORY_PRESSURE_LOW_RATIO {
+                MemoryPressure::Low
+            } else if available_ratio >= MEMORY_PRESSURE_MEDIUM_RATIO {
+                MemoryPressure::Medium
+            } else {
+                MemoryPressure::High
+            }
+        }
+
         /// Checks if the hardware meets the minimum requirements
         pub fn meets_requirements(&self, reqs: &SystemRequirements) -> Result<(), HardwareError> {
+            match self.check_all(reqs).failures.into_iter().next() {
+                Some(failure) => Err(HardwareError::CompatibilityError(failure)),
+                None => Ok(()),
+            }
+        }
+
+        /// Checks every requirement and reports all unmet ones instead of stopping at the first
+        pub fn check_all(&self, reqs: &SystemRequirements) -> CompatibilityReport {
+            let mut failures = Vec::new();
+
             if self.cpu_count < reqs.min_cpu_cores {
-                return Err(HardwareError::CompatibilityError(
-                    format!("Insufficient CPU cores. Required: {}, Available: {}", 
-                        reqs.min_cpu_cores, self.cpu_count)
-                ));
-            }
-            if self.memory_total < reqs.min_memory_kb {
-                return Err(HardwareError::CompatibilityError(
-                    format!("Insufficient memory. Required: {} KB, Available: {} KB", 
-                        reqs.min_memory_kb, self.memory_total)
-                ));
-            }
-
-            // Map platform names for compatibility check
-            let platform_to_check = match self.platform.as_str() {
-                "darwin" => "macos",
-                other => other,
-            };
+                failures.push(format!("Insufficient CPU cores. Required: {}, Available: {}", 
+                    reqs.min_cpu_cores, self.cpu_count));
+            }
 
-            if !reqs.supported_platforms.iter().any(|p| p == platform_to_check) {
-                return Err(HardwareError::CompatibilityError(
-                    format!("Unsupported platform: {}. Supported platforms: {}", 
-                        self.platform, reqs.supported_platforms.join(", "))
-                ));
+            let memory_available = Bytes(self.effective_memory_total());
+            let memory_required = reqs.min_memory();
+            if memory_available < Bytes::from(memory_required) {
+                failures.push(format!("Insufficient memory. Required: {} KB, Available: {} KB", 
+                    memory_required.0, memory_available.to_kib().0));
             }
-            Ok(())
-        }
-    }
 
-    /// Maximum number of retries for hardware info retrieval
-    const MAX_RETRIES: u32 = 3;
-    /// Delay between retries in milliseconds
-    const RETRY_DELAY_MS: u64 = 1000;
+            if !reqs.supports(&self.platform) {
+                let supported: Vec<&str> = reqs.supported_platforms.iter().map(Platform::as_str).collect();
+                failures.push(format!("Unsupported platform: {}. Supported platforms: {}", 
+                    self.platform, supported.join(", ")));
+            }
 
-    /// Retrieves current hardware information with retry logic
-    pub fn get_hardware_info() -> Result<HardwareInfo, HardwareError> {
-        let mut last_error = None;
-        for attempt in 1..=MAX_RETRIES {
-            match try_get_hardware_info() {
-                Ok(info) => {
-                    // Validate the information
-                    if let Err(e) = info.validate() {
-                        last_error = Some(e);
-                        if attempt == MAX_RETRIES {
-                            break;
-                        }
-                        thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
-                        continue;
-                    }
-                    return Ok(info);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt == MAX_RETRIES {
-                        break;
-                    }
-                    thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
-                }
+            CompatibilityReport {
+                passed: failures.is_empty(),
+                failures,
             }
         }
 
-        Err(last_error.unwrap_or_else(|| 
-            HardwareError::SystemError("Failed to retrieve hardware information after multiple attempts".to_string())
-        ))
-    }
+        /// Lists the fields that differ from `other`, sorted by field name.
+        ///
+        /// Fields are compared through their JSON form, so names are the camelCase names
+        /// the frontend sees and new fields are covered automatically. `collectedAt` is
+        /// skipped since it differs between any two readings.
+        pub fn diff(&self, other: &HardwareInfo) -> Vec<FieldChange> {
+            let (Ok(serde_json::Value::Object(before)), Ok(serde_json::Value::Object(after))) =
+                (serde_json::to_value(self), serde_json::to_value(other))
+            else {
+                return Vec::new();
+            };
 
-    /// Internal function to attempt hardware info retrieval
-    fn try_get_hardware_info() -> Result<HardwareInfo, HardwareError> {
-        let mut sys = System::new_all();
-        
-        // Refresh system information multiple times to ensure accuracy
-        for _ in 0..3 {
-            sys.refresh_all();
-            thread::sleep(Duration::from_millis(100));
+            let null = serde_json::Value::Null;
+            let mut changes: Vec<FieldChange> = before.keys()
+                .chain(after.keys().filter(|field| !before.contains_key(*field)))
+                .filter(|field| *field != "collectedAt")
+                .filter_map(|field| {
+                    let from = before.get(field).unwrap_or(&null);
+                    let to = after.get(field).unwrap_or(&null);
+                    (from != to).then(|| FieldChange {
+                        field: field.clone(),
+                        from: display_json_value(from),
+                        to: display_json_value(to),
+                    })
+                })
+                .collect();
+            // Tauri enables serde_json's preserve_order, so map order is declaration order
+            changes.sort_by(|a, b| a.field.cmp(&b.field));
+            changes
         }
+    }
 
-        // Get CPU information with error handling
-        let cpu_count = sys.cpus().len();
-        if cpu_count == 0 {
-            return Err(HardwareError::CpuError("No CPU cores detected".to_string()));
+    /// A field whose value differs between two `HardwareInfo` snapshots
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct FieldChange {
+        pub field: String,
+        pub from: String,
+        pub to: String,
+    }
+
+    /// Renders a JSON value for display, without quotes around strings
+    fn display_json_value(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Null => "none".to_string(),
+            other => other.to_string(),
         }
+    }
 
-        let cpu_brand = sys.cpus()
-            .first()
-            .map(|cpu| cpu.brand().trim().to_string())
-            .filter(|brand| !brand.is_empty())
-            .ok_or_else(|| HardwareError::CpuError("Failed to retrieve CPU information".to_string()))?;
+    /// Available/total memory ratio at or above which pressure is low
+    const MEMORY_PRESSURE_LOW_RATIO: f64 = 0.25;
+    /// Available/total memory ratio at or above which pressure is medium
+    const MEMORY_PRESSURE_MEDIUM_RATIO: f64 = 0.10;
 
-        // Get memory information with error handling
-        let memory_total = sys.total_memory();
-        let memory_used = sys.used_memory();
+    /// How close the system is to running out of memory
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+    pub enum MemoryPressure {
+        /// At least 25% of memory is available
+        Low,
+        /// Between 10% and 25% of memory is available
+        Medium,
+        /// Less than 10% of memory is available
+        High,
+    }
 
-        if memory_total == 0 {
-            return Err(HardwareError::MemoryError("Failed to detect system memory".to_string()));
-        }
+    /// Outcome of checking hardware against every system requirement
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct CompatibilityReport {
+        pub passed: bool,
+        /// One message per unmet requirement, in the order they were checked
+        pub failures: Vec<String>,
+    }
 
-        // Get platform information with proper mapping for macOS
-        let platform = match std::env::consts::OS {
-            "macos" => "macos".to_string(),
-            os => os.to_string(),
+    /// Battery status for laptops and other battery-powered machines
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct BatteryInfo {
+        /// State of charge, from 0 to 100
+        #[serde(rename = "chargePercent")]
+        pub charge_percent: Option<f32>,
+        #[serde(rename = "isCharging")]
+        pub is_charging: bool,
+        /// Estimated time until the battery is empty, only known while discharging
+        #[serde(rename = "timeToEmpty")]
+        pub time_to_empty: Option<Duration>,
+    }
+
+    /// Retrieves the status of the first battery in the system.
+    ///
+    /// Returns `Ok(None)` on machines without a battery, including when the platform
+    /// offers no way to enumerate batteries at all.
+    pub fn get_battery_info() -> Result<Option<BatteryInfo>, HardwareError> {
+        use starship_battery::units::{ratio::percent, time::second};
+
+        let batteries = match starship_battery::Manager::new().and_then(|manager| manager.batteries()) {
+            Ok(batteries) => batteries,
+            Err(_) => return Ok(None),
         };
 
-        let info = HardwareInfo {
-            cpu_count,
-            cpu_brand,
-            memory_total,
-            memory_used,
-            platform,
+        let battery = match batteries.into_iter().next() {
+            Some(battery) => battery
+                .map_err(|e| HardwareError::SystemError(format!("Failed to read battery status: {}", e)))?,
+            None => return Ok(None),
         };
 
-        // Validate before returning
-        info.validate()?;
-        Ok(info)
+        let charge_percent = Some(battery.state_of_charge().get::<percent>()).filter(|value| value.is_finite());
+        let time_to_empty = battery
+            .time_to_empty()
+            .map(|time| time.get::<second>())
+            .filter(|secs| secs.is_finite() && *secs >= 0.0)
+            .map(Duration::from_secs_f32);
+
+        Ok(Some(BatteryInfo {
+            charge_percent,
+            is_charging: battery.state() == starship_battery::State::Charging,
+            time_to_empty,
+        }))
     }
 
-    /// Checks if the system is compatible with the application
-    pub fn check_system_compatibility() -> Result<(), HardwareError> {
-        let info = get_hardware_info()?;
-        info.meets_requirements(&SystemRequirements::default())
+    /// How long the machine has been running
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct UptimeInfo {
+        #[serde(rename = "uptimeSeconds")]
+        pub uptime_seconds: u64,
+        /// Boot time as an ISO-8601 (RFC 3339) UTC timestamp
+        #[serde(rename = "bootTime")]
+        pub boot_time: String,
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::hardware::{self, HardwareInfo, HardwareError, SystemRequirements};
-    use std::thread;
-    use std::time::Duration;
+    /// Retrieves system uptime and boot time
+    pub fn get_uptime() -> Result<UptimeInfo, HardwareError> {
+        let sys = System::new();
+        let boot_secs = i64::try_from(sys.boot_time())
+            .map_err(|_| HardwareError::SystemError("Boot time out of range".to_string()))?;
+        let boot_time = chrono::DateTime::from_timestamp(boot_secs, 0)
+            .ok_or_else(|| HardwareError::SystemError("Invalid boot time".to_string()))?;
 
-    #[test]
-    fn test_hardware_info_basic() {
-        let info = hardware::get_hardware_info().expect("Should get hardware info");
-        assert!(info.cpu_count > 0, "System should have at least one CPU core");
-        assert!(!info.cpu_brand.is_empty(), "CPU brand should not be empty");
-        assert!(info.memory_total > 0, "Total memory should be greater than 0");
-        assert!(info.memory_used <= info.memory_total, "Used memory should not exceed total memory");
+        Ok(UptimeInfo {
+            uptime_seconds: sys.uptime(),
+            boot_time: boot_time.to_rfc3339(),
+        })
     }
 
-    #[test]
-    fn test_system_compatibility() {
-        let result = hardware::check_system_compatibility();
-        assert!(result.is_ok(), "System should meet minimum requirements");
+    /// System load averages over the last 1, 5 and 15 minutes
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct LoadAverage {
+        pub one: f64,
+        pub five: f64,
+        pub fifteen: f64,
     }
 
-    #[test]
-    fn test_custom_requirements() {
-        let info = hardware::get_hardware_info().expect("Should get hardware info");
-        let reqs = SystemRequirements {
-            min_cpu_cores: info.cpu_count + 1, // Impossible requirement
-            min_memory_kb: 1024,
-            supported_platforms: vec!["windows".to_string(), "macos".to_string()],
-        };
-        let result = info.meets_requirements(&reqs);
-        assert!(result.is_err(), "Should fail with impossible CPU requirement");
-    }
+    /// Retrieves the system load averages.
+    ///
+    /// Returns `None` on Windows, which has no load average concept.
+    pub fn get_load_average() -> Option<LoadAverage> {
+        if cfg!(target_os = "windows") {
+            return None;
+        }
 
-    #[test]
-    fn test_hardware_info_memory_consistency() {
-        let info1 = hardware::get_hardware_info().expect("Should get first hardware info");
-        thread::sleep(Duration::from_millis(100));
-        let info2 = hardware::get_hardware_info().expect("Should get second hardware info");
+        let load = System::new().load_average();
+        Some(LoadAverage {
+            one: load.one,
+            five: load.five,
+            fifteen: load.fifteen,
+        })
+    }
 
-        assert_eq!(info1.memory_total, info2.memory_total, "Total memory should remain constant");
-        assert!(info1.cpu_count == info2.cpu_count, "CPU count should remain constant");
+    /// Lightweight summary of a running process
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct ProcessSummary {
+        pub pid: u32,
+        pub name: String,
+        #[serde(rename = "memoryKb")]
+        pub memory_kb: u64,
+        /// CPU usage, where 100% is one fully used core
+        #[serde(rename = "cpuPercent")]
+        pub cpu_percent: f32,
     }
 
-    #[test]
-    fn test_hardware_info_serialization() {
-        let info = hardware::get_hardware_info().expect("Should get hardware info");
-        let serialized = serde_json::to_string(&info).expect("Failed to serialize HardwareInfo");
-        let deserialized: hardware::HardwareInfo = serde_json::from_str(&serialized).expect("Failed to deserialize HardwareInfo");
-        
-        assert_eq!(info, deserialized, "Serialization/deserialization should preserve data");
+    /// Returns the `n` processes using the most memory, largest first.
+    ///
+    /// Processes are sampled twice, `MINIMUM_CPU_UPDATE_INTERVAL` apart, so that
+    /// `cpu_percent` reflects actual usage instead of always being zero.
+    pub fn get_top_processes(n: usize) -> Vec<ProcessSummary> {
+        let mut sys = System::new();
+        sys.refresh_processes();
+        thread::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_processes();
+
+        let mut processes: Vec<ProcessSummary> = sys.processes()
+            .values()
+            .map(|process| ProcessSummary {
+                pid: process.pid().as_u32(),
+                name: process.name().to_string(),
+                memory_kb: Bytes(process.memory()).to_kib().0,
+                cpu_percent: process.cpu_usage(),
+            })
+            .collect();
+
+        processes.sort_by_key(|process| std::cmp::Reverse(process.memory_kb));
+        processes.truncate(n);
+        processes
     }
 
-    #[test]
-    fn test_memory_values_sanity() {
-        let info = hardware::get_hardware_info().expect("Should get hardware info");
-        // Most modern systems have at least 1GB of RAM
-        assert!(info.memory_total >= 1024 * 1024, "Total memory should be at least 1GB");
-        // Used memory should be non-zero on a running system
-        assert!(info.memory_used > 0, "Used memory should be greater than 0");
-        // Used memory should not exceed total memory
-        assert!(info.memory_used <= info.memory_total, "Used memory should not exceed total memory");
+    /// One installed memory module (DIMM)
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct MemoryModule {
+        #[serde(rename = "sizeBytes")]
+        pub size_bytes: u64,
+        #[serde(rename = "speedMhz")]
+        pub speed_mhz: Option<u32>,
+        pub manufacturer: Option<String>,
+        pub slot: Option<String>,
     }
 
-    #[test]
-    fn test_hardware_info_validation() {
-        // Test invalid CPU count
-        let invalid_cpu = HardwareInfo {
-            cpu_count: 0,
-            cpu_brand: "Test CPU".to_string(),
-            memory_total: 1024,
-            memory_used: 512,
-            platform: "windows".to_string(),
+    /// Lists installed memory modules.
+    ///
+    /// Sourced from `dmidecode` on Linux (which usually needs root), WMI on Windows
+    /// and `system_profiler` on macOS. Returns an empty list when the tool is missing,
+    /// denied, or reports nothing.
+    pub fn get_memory_modules() -> Vec<MemoryModule> {
+        let (program, args): (&str, &[&str]) = if cfg!(target_os = "linux") {
+            ("dmidecode", &["--type", "17"])
+        } else if cfg!(target_os = "macos") {
+            ("system_profiler", &["-json", "SPMemoryDataType"])
+        } else if cfg!(target_os = "windows") {
+            ("powershell", &[
+                "-NoProfile",
+                "-Command",
+                "Get-CimInstance Win32_PhysicalMemory | Select-Object Capacity,Speed,Manufacturer,DeviceLocator | ConvertTo-Json",
+            ])
+        } else {
+            return Vec::new();
         };
-        assert!(invalid_cpu.validate().is_err(), "Should fail with zero CPU count");
 
-        // Test invalid CPU brand
-        let invalid_brand = HardwareInfo {
-            cpu_count: 1,
-            cpu_brand: "".to_string(),
-            memory_total: 1024,
-            memory_used: 512,
-            platform: "windows".to_string(),
+        let output = match std::process::Command::new(program).args(args).output() {
+            Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout).into_owned(),
+            _ => return Vec::new(),
         };
-        assert!(invalid_brand.validate().is_err(), "Should fail with empty CPU brand");
 
-        // Test invalid memory total
-        let invalid_memory = HardwareInfo {
-            cpu_count: 1,
-            cpu_brand: "Test CPU".to_string(),
-            memory_total: 0,
-            memory_used: 0,
-            platform: "windows".to_string(),
-        };
-        assert!(invalid_memory.validate().is_err(), "Should fail with zero total memory");
+        if cfg!(target_os = "linux") {
+            parse_dmidecode_memory(&output)
+        } else if cfg!(target_os = "macos") {
+            parse_system_profiler_memory(&output)
+        } else {
+            parse_wmi_memory(&output)
+        }
+    }
 
-        // Test invalid memory usage
-        let invalid_usage = HardwareInfo {
-            cpu_count: 1,
-            cpu_brand: "Test CPU".to_string(),
-            memory_total: 1024,
-            memory_used: 2048,
-            platform: "windows".to_string(),
+    /// Parses a size such as "16 GB" or "8192 MB" into bytes, using binary units
+    fn parse_memory_size(size: &str) -> Option<u64> {
+        let mut parts = size.split_whitespace();
+        let value: u64 = parts.next()?.parse().ok()?;
+        let bytes = match parts.next()?.to_ascii_uppercase().as_str() {
+            "TB" => Bytes::from(Mib(value.saturating_mul(1024 * 1024))),
+            "GB" => Bytes::from(Mib(value.saturating_mul(1024))),
+            "MB" => Bytes::from(Mib(value)),
+            "KB" => Bytes::from(Kib(value)),
+            _ => return None,
         };
-        assert!(invalid_usage.validate().is_err(), "Should fail when used memory exceeds total");
+        Some(bytes.0).filter(|bytes| *bytes > 0)
+    }
+
+    /// Parses the leading number of a speed such as "3200 MT/s" or "2667 MHz"
+    fn parse_memory_speed(speed: &str) -> Option<u32> {
+        speed.split_whitespace().next()?.parse().ok().filter(|mhz| *mhz > 0)
+    }
+
+    /// Drops the placeholders vendor tools print for unknown values
+    fn known_value(value: &str) -> Option<String> {
+        let value = value.trim();
+        match value.to_ascii_lowercase().as_str() {
+            "" | "unknown" | "not specified" | "undefined" | "empty" => None,
+            _ => Some(value.to_string()),
+        }
+    }
+
+    /// Parses `dmidecode --type 17` output, skipping empty slots
+    pub fn parse_dmidecode_memory(output: &str) -> Vec<MemoryModule> {
+        output.split("Memory Device")
+            .skip(1)
+            .filter_map(|device| {
+                let field = |name: &str| {
+                    device.lines()
+                        .filter_map(|line| line.trim().split_once(": "))
+                        .find(|(key, _)| *key == name)
+                        .map(|(_, value)| value.trim())
+                };
+                Some(MemoryModule {
+                    size_bytes: parse_memory_size(field("Size")?)?,
+                    speed_mhz: field("Configured Memory Speed")
+                        .and_then(parse_memory_speed)
+                        .or_else(|| field("Speed").and_then(parse_memory_speed)),
+                    manufacturer: field("Manufacturer").and_then(known_value),
+                    slot: field("Locator").and_then(known_value),
+                })
+            })
+            .collect()
+    }
+
+    /// Parses `system_profiler -json SPMemoryDataType` output.
+    ///
+    /// Intel Macs list one item per slot; Apple Silicon reports a single unified
+    /// memory entry with no slot.
+    pub fn parse_system_profiler_memory(output: &str) -> Vec<MemoryModule> {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(output) else {
+            return Vec::new();
+        };
+        let text = |value: &serde_json::Value, key: &str| value.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+        let mut modules = Vec::new();
+        for entry in json["SPMemoryDataType"].as_array().into_iter().flatten() {
+            if let Some(items) = entry.get("_items").and_then(|items| items.as_array()) {
+                modules.extend(items.iter().filter_map(|item| Some(MemoryModule {
+                    size_bytes: parse_memory_size(item.get("dimm_size")?.as_str()?)?,
+                    speed_mhz: text(item, "dimm_speed").as_deref().and_then(parse_memory_speed),
+                    manufacturer: text(item, "dimm_manufacturer").as_deref().and_then(known_value),
+                    slot: text(item, "_name").as_deref().and_then(known_value),
+                })));
+            } else if let Some(size) = text(entry, "SPMemoryDataType").as_deref().and_then(parse_memory_size) {
+                modules.push(MemoryModule {
+                    size_bytes: size,
+                    speed_mhz: None,
+                    manufacturer: text(entry, "dimm_manufacturer").as_deref().and_then(known_value),
+                    slot: None,
+                });
+            }
+        }
+        modules
+    }
+
+    /// Parses `Win32_PhysicalMemory` objects converted to JSON by PowerShell, which
+    /// emits a bare object instead of an array when there is a single module
+    pub fn parse_wmi_memory(output: &str) -> Vec<MemoryModule> {
+        let items = match serde_json::from_str::<serde_json::Value>(output) {
+            Ok(serde_json::Value::Array(items)) => items,
+            Ok(item @ serde_json::Value::Object(_)) => vec![item],
+            _ => return Vec::new(),
+        };
+
+        items.iter()
+            .filter_map(|item| Some(MemoryModule {
+                size_bytes: item.get("Capacity")?.as_u64().filter(|bytes| *bytes > 0)?,
+                speed_mhz: item.get("Speed")
+                    .and_then(|speed| speed.as_u64())
+                    .and_then(|speed| u32::try_from(speed).ok())
+                    .filter(|mhz| *mhz > 0),
+                manufacturer: item.get("Manufacturer").and_then(|v| v.as_str()).and_then(known_value),
+                slot: item.get("DeviceLocator").and_then(|v| v.as_str()).and_then(known_value),
+            }))
+            .collect()
+    }
+
+    /// `/proc/diskstats` counts sectors of 512 bytes regardless of the device's sector size
+    const DISKSTATS_SECTOR_BYTES: u64 = 512;
+
+    /// Read and write throughput of one block device
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct DiskIo {
+        pub name: String,
+        #[serde(rename = "readBytesPerSec")]
+        pub read_bytes_per_sec: f64,
+        #[serde(rename = "writeBytesPerSec")]
+        pub write_bytes_per_sec: f64,
+    }
+
+    /// Disk throughput averaged over a sampling window
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct DiskIoStats {
+        pub disks: Vec<DiskIo>,
+    }
+
+    /// Parses `/proc/diskstats` into (device, bytes read, bytes written).
+    ///
+    /// Loop and RAM devices are skipped since they don't reflect storage speed.
+    pub fn parse_diskstats(contents: &str) -> Vec<(String, u64, u64)> {
+        contents.lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let name = *fields.get(2)?;
+                if name.starts_with("loop") || name.starts_with("ram") {
+                    return None;
+                }
+                let sectors_read: u64 = fields.get(5)?.parse().ok()?;
+                let sectors_written: u64 = fields.get(9)?.parse().ok()?;
+                Some((
+                    name.to_string(),
+                    sectors_read.saturating_mul(DISKSTATS_SECTOR_BYTES),
+                    sectors_written.saturating_mul(DISKSTATS_SECTOR_BYTES),
+                ))
+            })
+            .collect()
+    }
+
+    /// Computes per-disk rates between two `parse_diskstats` snapshots.
+    ///
+    /// A counter that went backwards (device reset or 32-bit wraparound) reports a
+    /// rate of zero for that window rather than a bogus huge value.
+    pub fn disk_io_between(
+        before: &[(String, u64, u64)],
+        after: &[(String, u64, u64)],
+        elapsed: Duration,
+    ) -> DiskIoStats {
+        let seconds = elapsed.as_secs_f64();
+        let rate = |start: u64, end: u64| match end.checked_sub(start) {
+            Some(delta) if seconds > 0.0 => delta as f64 / seconds,
+            _ => 0.0,
+        };
+
+        let disks = after.iter()
+            .filter_map(|(name, read, written)| {
+                let (_, read_before, written_before) = before.iter().find(|(n, _, _)| n == name)?;
+                Some(DiskIo {
+                    name: name.clone(),
+                    read_bytes_per_sec: rate(*read_before, *read),
+                    write_bytes_per_sec: rate(*written_before, *written),
+                })
+            })
+            .collect();
+        DiskIoStats { disks }
+    }
+
+    /// Samples disk throughput over `duration`, blocking the calling thread.
+    ///
+    /// sysinfo 0.29 has no per-disk I/O counters, so this reads `/proc/diskstats`
+    /// and returns no disks on other platforms.
+    pub fn sample_disk_io(duration: Duration) -> DiskIoStats {
+        let read = || fs::read_to_string("/proc/diskstats")
+            .map(|contents| parse_diskstats(&contents))
+            .unwrap_or_default();
+
+        let before = read();
+        let start = Instant::now();
+        thread::sleep(duration);
+        let after = read();
+        disk_io_between(&before, &after, start.elapsed())
+    }
+
+    /// SIMD instruction sets relevant to CPU inference kernels
+    #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct CpuFeatures {
+        pub avx2: bool,
+        pub avx512f: bool,
+        pub fma: bool,
+        pub neon: bool,
+    }
+
+    /// Detects SIMD support of the running CPU.
+    ///
+    /// Features of another architecture are always reported as unsupported.
+    pub fn get_cpu_features() -> CpuFeatures {
+        #[allow(unused_mut)]
+        let mut features = CpuFeatures::default();
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            features.avx2 = std::arch::is_x86_feature_detected!("avx2");
+            features.avx512f = std::arch::is_x86_feature_detected!("avx512f");
+            features.fma = std::arch::is_x86_feature_detected!("fma");
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            features.neon = std::arch::is_aarch64_feature_detected!("neon");
+            // Fused multiply-add is part of the baseline AArch64 SIMD instruction set
+            features.fma = features.neon;
+        }
+
+        features
+    }
+
+    /// Whether the process runs inside a virtual machine and/or a container
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct VirtualizationInfo {
+        #[serde(rename = "isVirtual")]
+        pub is_virtual: bool,
+        /// Hypervisor name (e.g. "KVM", "Hyper-V", "WSL"), when one was identified
+        pub hypervisor: Option<String>,
+        #[serde(rename = "isContainer")]
+        pub is_container: bool,
+    }
+
+    /// Detects virtualization and containerization on a best-effort basis.
+    ///
+    /// Uses the CPUID hypervisor bit on x86, container marker files and
+    /// `/proc/1/cgroup` for containers, and the kernel release for WSL. Detection
+    /// that isn't available on the current platform simply reports nothing.
+    pub fn detect_virtualization() -> VirtualizationInfo {
+        detect_virtualization_in(Path::new("/"))
+    }
+
+    /// Detects virtualization, reading container and WSL markers below `root`
+    pub fn detect_virtualization_in(root: &Path) -> VirtualizationInfo {
+        let is_wsl = fs::read_to_string(root.join("proc/sys/kernel/osrelease"))
+            .map(|release| {
+                let release = release.to_lowercase();
+                release.contains("microsoft") || release.contains("wsl")
+            })
+            .unwrap_or(false);
+
+        let hypervisor = if is_wsl {
+            Some("WSL".to_string())
+        } else {
+            cpuid_hypervisor()
+        };
+
+        VirtualizationInfo {
+            is_virtual: hypervisor.is_some(),
+            hypervisor,
+            is_container: is_container_in(root),
+        }
+    }
+
+    /// Checks for container runtime marker files and container cgroup paths below `root`
+    fn is_container_in(root: &Path) -> bool {
+        if root.join(".dockerenv").exists() || root.join("run/.containerenv").exists() {
+            return true;
+        }
+
+        fs::read_to_string(root.join("proc/1/cgroup"))
+            .map(|cgroup| cgroup_indicates_container(&cgroup))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the contents of a `/proc/<pid>/cgroup` file point at a container runtime
+    pub fn cgroup_indicates_container(cgroup: &str) -> bool {
+        const MARKERS: [&str; 6] = ["docker", "kubepods", "containerd", "lxc", "podman", "libpod"];
+        cgroup.lines().any(|line| {
+            let path = line.rsplit(':').next().unwrap_or("");
+            MARKERS.iter().any(|marker| path.contains(marker))
+        })
+    }
+
+    /// Identifies the hypervisor through the CPUID hypervisor leaf
+    #[cfg(target_arch = "x86_64")]
+    fn cpuid_hypervisor() -> Option<String> {
+        use std::arch::x86_64::__cpuid;
+
+        // `__cpuid` is only unsafe on older toolchains
+        #[allow(unused_unsafe)]
+        let (features, vendor_leaf) = unsafe { (__cpuid(1), __cpuid(0x4000_0000)) };
+        if features.ecx & (1 << 31) == 0 {
+            return None;
+        }
+
+        let mut vendor = Vec::with_capacity(12);
+        for register in [vendor_leaf.ebx, vendor_leaf.ecx, vendor_leaf.edx] {
+            vendor.extend_from_slice(&register.to_le_bytes());
+        }
+        let vendor = String::from_utf8_lossy(&vendor).trim_matches(char::from(0)).trim().to_string();
+
+        let name = match vendor.as_str() {
+            "KVMKVMKVM" => "KVM",
+            "Microsoft Hv" => "Hyper-V",
+            "VMwareVMware" => "VMware",
+            "XenVMMXenVMM" => "Xen",
+            "VBoxVBoxVBox" => "VirtualBox",
+            "TCGTCGTCGTCG" => "QEMU",
+            "lrpepyh  vr" => "Parallels",
+            "bhyve bhyve" => "bhyve",
+            "" => "Unknown",
+            other => other,
+        };
+        Some(name.to_string())
+    }
+
+    /// Identifies the hypervisor through the CPUID hypervisor leaf
+    #[cfg(not(target_arch = "x86_64"))]
+    fn cpuid_hypervisor() -> Option<String> {
+        None
+    }
+
+    /// How the process runs relative to Apple Silicon
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+    pub enum AppleRuntime {
+        /// Native arm64 build on Apple Silicon
+        Native,
+        /// x86_64 build translated by Rosetta 2 on Apple Silicon
+        Rosetta,
+        /// Not running on Apple Silicon (including Intel Macs)
+        NotApple,
+    }
+
+    /// Detects whether the process runs natively on Apple Silicon or under Rosetta 2.
+    ///
+    /// `sysctl.proc_translated` is 1 for translated processes and 0 otherwise; it's
+    /// missing on macOS releases that predate Apple Silicon.
+    #[cfg(target_os = "macos")]
+    pub fn detect_apple_runtime() -> AppleRuntime {
+        let translated = std::process::Command::new("sysctl")
+            .args(["-n", "sysctl.proc_translated"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        match translated.as_deref() {
+            Some("1") => AppleRuntime::Rosetta,
+            _ if cfg!(target_arch = "aarch64") => AppleRuntime::Native,
+            _ => AppleRuntime::NotApple,
+        }
+    }
+
+    /// Detects whether the process runs natively on Apple Silicon or under Rosetta 2
+    #[cfg(not(target_os = "macos"))]
+    pub fn detect_apple_runtime() -> AppleRuntime {
+        AppleRuntime::NotApple
+    }
+
+    /// Mount point of the cgroup filesystem on Linux
+    const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+    /// cgroup v1 reports "no limit" as a page-aligned i64::MAX
+    const CGROUP_V1_UNLIMITED: u64 = 0x7FFF_FFFF_FFFF_F000;
+
+    /// Reads the memory limit of the cgroup the process runs in, if any
+    pub fn read_cgroup_memory_limit() -> Option<u64> {
+        read_cgroup_memory_limit_from(Path::new(CGROUP_ROOT))
+    }
+
+    /// Reads the cgroup memory limit below the given cgroup root.
+    ///
+    /// Checks cgroup v2 `memory.max` first and falls back to cgroup v1
+    /// `memory/memory.limit_in_bytes`. Returns `None` when the files are missing
+    /// or the cgroup is unlimited.
+    pub fn read_cgroup_memory_limit_from(root: &Path) -> Option<u64> {
+        if let Ok(contents) = fs::read_to_string(root.join("memory.max")) {
+            let value = contents.trim();
+            if value == "max" {
+                return None;
+            }
+            return value.parse().ok();
+        }
+
+        fs::read_to_string(root.join("memory").join("memory.limit_in_bytes"))
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|limit| *limit < CGROUP_V1_UNLIMITED)
+    }
+
+    /// Location of the per-CPU cpufreq directories on Linux
+    #[cfg(target_os = "linux")]
+    const CPUFREQ_ROOT: &str = "/sys/devices/system/cpu";
+
+    /// Reads the maximum rated CPU frequency in MHz
+    #[cfg(target_os = "linux")]
+    pub fn read_cpu_max_frequency_mhz() -> Option<u64> {
+        read_cpu_max_frequency_mhz_from(Path::new(CPUFREQ_ROOT))
+    }
+
+    /// Reads the maximum rated CPU frequency in MHz
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    pub fn read_cpu_max_frequency_mhz() -> Option<u64> {
+        None
+    }
+
+    /// Reads the maximum rated CPU frequency in MHz.
+    ///
+    /// `hw.cpufrequency_max` only exists on Intel Macs.
+    #[cfg(target_os = "macos")]
+    pub fn read_cpu_max_frequency_mhz() -> Option<u64> {
+        let output = std::process::Command::new("sysctl")
+            .args(["-n", "hw.cpufrequency_max"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let hz: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+        Some(hz / 1_000_000).filter(|mhz| *mhz > 0)
+    }
+
+    /// Reads cpu0's `cpuinfo_max_freq` (in kHz) below the given sysfs CPU directory
+    pub fn read_cpu_max_frequency_mhz_from(root: &Path) -> Option<u64> {
+        let khz: u64 = fs::read_to_string(root.join("cpu0").join("cpufreq").join("cpuinfo_max_freq"))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(khz / 1000).filter(|mhz| *mhz > 0)
+    }
+
+    /// Reads the CPU quota of the cgroup the process runs in, in cores, if any
+    pub fn read_cgroup_cpu_limit() -> Option<f64> {
+        read_cgroup_cpu_limit_from(Path::new(CGROUP_ROOT))
+    }
+
+    /// Reads the cgroup CPU quota below the given cgroup root.
+    ///
+    /// Checks cgroup v2 `cpu.max` ("<quota> <period>" or "max <period>") first and
+    /// falls back to cgroup v1 `cpu.cfs_quota_us`/`cpu.cfs_period_us`, where a quota
+    /// of -1 means unlimited.
+    pub fn read_cgroup_cpu_limit_from(root: &Path) -> Option<f64> {
+        let cores = |quota: f64, period: f64| (quota > 0.0 && period > 0.0).then(|| quota / period);
+
+        if let Ok(contents) = fs::read_to_string(root.join("cpu.max")) {
+            let mut fields = contents.split_whitespace();
+            let quota = fields.next()?;
+            if quota == "max" {
+                return None;
+            }
+            let period = fields.next().unwrap_or("100000");
+            return cores(quota.parse().ok()?, period.parse().ok()?);
+        }
+
+        let read = |name: &str| -> Option<f64> {
+            fs::read_to_string(root.join("cpu").join(name)).ok()?.trim().parse().ok()
+        };
+        cores(read("cpu.cfs_quota_us")?, read("cpu.cfs_period_us")?)
+    }
+
+    /// Picks the CPU temperature out of labelled sensor readings.
+    ///
+    /// Prefers a CPU package sensor and otherwise averages every CPU/core sensor.
+    /// Readings that are not finite or not above zero are treated as missing.
+    pub fn cpu_temperature_from_sensors(sensors: &[(String, f32)]) -> Option<f32> {
+        let valid: Vec<(String, f32)> = sensors
+            .iter()
+            .filter(|(_, temp)| temp.is_finite() && *temp > 0.0)
+            .map(|(label, temp)| (label.to_lowercase(), *temp))
+            .collect();
+
+        if let Some((_, temp)) = valid.iter().find(|(label, _)| label.contains("package")) {
+            return Some(*temp);
+        }
+
+        let cpu_temps: Vec<f32> = valid
+            .iter()
+            .filter(|(label, _)| {
+                ["cpu", "core", "tctl", "tdie"].iter().any(|name| label.contains(name))
+            })
+            .map(|(_, temp)| *temp)
+            .collect();
+
+        if cpu_temps.is_empty() {
+            None
+        } else {
+            Some(cpu_temps.iter().sum::<f32>() / cpu_temps.len() as f32)
+        }
+    }
+
+    /// Maximum number of retries for hardware info retrieval
+    const MAX_RETRIES: u32 = 3;
+    /// Delay between retries in milliseconds
+    const RETRY_DELAY_MS: u64 = 1000;
+
+    #[cfg(debug_assertions)]
+    thread_local! {
+        /// Set while `run_blocking` work executes, where blocking calls are expected
+        static IN_BLOCKING_TASK: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Marks the current thread as running `run_blocking` work until dropped, so the
+    /// flag is cleared even if the work panics
+    #[cfg(debug_assertions)]
+    pub(crate) struct BlockingTaskGuard;
+
+    #[cfg(debug_assertions)]
+    impl BlockingTaskGuard {
+        pub(crate) fn enter() -> Self {
+            IN_BLOCKING_TASK.with(|flag| flag.set(true));
+            Self
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    impl Drop for BlockingTaskGuard {
+        fn drop(&mut self) {
+            IN_BLOCKING_TASK.with(|flag| flag.set(false));
+        }
+    }
+
+    /// Logs a warning when a blocking reader is called from a tokio runtime thread,
+    /// where its sleeps would stall the executor. Returns whether it warned.
+    #[cfg(debug_assertions)]
+    pub(crate) fn warn_if_on_async_runtime(caller: &str) -> bool {
+        if IN_BLOCKING_TASK.with(Cell::get) {
+            return false;
+        }
+        if tokio::runtime::Handle::try_current().is_err() {
+            return false;
+        }
+        tracing::warn!(
+            "{} blocks the calling thread but was called from an async runtime; \
+             use get_hardware_info_async or hardware::run_blocking instead",
+            caller
+        );
+        true
+    }
+
+    /// Release builds skip the async runtime check entirely
+    #[cfg(not(debug_assertions))]
+    pub(crate) fn warn_if_on_async_runtime(_caller: &str) -> bool {
+        false
+    }
+
+    /// Runs a blocking hardware reader on tokio's blocking thread pool.
+    ///
+    /// Use this rather than `tokio::task::spawn_blocking` directly, since blocking pool
+    /// threads also see the runtime and would otherwise trip the async runtime warning.
+    pub async fn run_blocking<F, T>(f: F) -> Result<T, HardwareError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            #[cfg(debug_assertions)]
+            let _guard = BlockingTaskGuard::enter();
+            f()
+        })
+        .await
+        .map_err(|e| HardwareError::SystemError(format!("Blocking hardware task failed: {}", e)))
+    }
+
+    /// Retrieves hardware information without blocking the async runtime
+    pub async fn get_hardware_info_async() -> Result<HardwareInfo, HardwareError> {
+        run_blocking(get_hardware_info).await?
+    }
+
+    /// Retrieves current hardware information with retry logic.
+    ///
+    /// This sleeps between refreshes and retries, so async code should use
+    /// `get_hardware_info_async`; debug builds warn when it's called on a runtime thread.
+    pub fn get_hardware_info() -> Result<HardwareInfo, HardwareError> {
+        warn_if_on_async_runtime("get_hardware_info");
+        let mut last_error = None;
+        for attempt in 1..=MAX_RETRIES {
+            match try_get_hardware_info() {
+                Ok(info) => {
+                    // Validate the information
+                    if let Err(e) = info.validate() {
+                        last_error = Some(e);
+                        if attempt == MAX_RETRIES {
+                            break;
+                        }
+                        thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
+                        continue;
+                    }
+                    return Ok(info);
+                }
+                Err(e) => {
+                    last_error = Some(e);
+                    if attempt == MAX_RETRIES {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(RETRY_DELAY_MS));
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| 
+            HardwareError::SystemError("Failed to retrieve hardware information after multiple attempts".to_string())
+        ))
+    }
+
+    /// Long-lived `System` that only tracks the CPU, memory and sensor data
+    /// `HardwareInfo` is built from
+    pub(crate) struct WarmSystem {
+        /// The system and when it was last refreshed
+        state: Mutex<(System, Instant)>,
+    }
+
+    impl WarmSystem {
+        /// Loads the CPU list, memory and sensors and takes the first CPU sample
+        pub(crate) fn new() -> Self {
+            let refresh = RefreshKind::new()
+                .with_cpu(CpuRefreshKind::everything())
+                .with_memory()
+                .with_components_list();
+            let sys = System::new_with_specifics(refresh);
+            Self {
+                state: Mutex::new((sys, Instant::now())),
+            }
+        }
+
+        /// Refreshes the tracked data and reads hardware information from it
+        pub(crate) fn read(&self) -> Result<HardwareInfo, HardwareError> {
+            self.read_within(Duration::ZERO)
+        }
+
+        /// Reads hardware information, refreshing the tracked data first unless it was
+        /// refreshed less than `max_age` ago
+        pub(crate) fn read_within(&self, max_age: Duration) -> Result<HardwareInfo, HardwareError> {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let (sys, refreshed_at) = &mut *state;
+            if refreshed_at.elapsed() >= max_age {
+                Self::refresh_state(sys, refreshed_at);
+            }
+            read_hardware_info(sys)
+        }
+
+        /// Refreshes the tracked data now
+        pub(crate) fn refresh(&mut self) {
+            let (sys, refreshed_at) = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+            Self::refresh_state(sys, refreshed_at);
+        }
+
+        /// When the tracked data was last refreshed
+        pub(crate) fn refreshed_at(&self) -> Instant {
+            self.state.lock().unwrap_or_else(|e| e.into_inner()).1
+        }
+
+        /// Gives tests read access to the tracked system
+        #[cfg(test)]
+        pub(crate) fn with_system<R>(&self, f: impl FnOnce(&System) -> R) -> R {
+            f(&self.state.lock().unwrap_or_else(|e| e.into_inner()).0)
+        }
+
+        /// CPU usage is only meaningful once `MINIMUM_CPU_UPDATE_INTERVAL` has passed
+        /// since the previous sample, so refreshes closer together than that wait it out.
+        fn refresh_state(sys: &mut System, refreshed_at: &mut Instant) {
+            let wait = System::MINIMUM_CPU_UPDATE_INTERVAL.saturating_sub(refreshed_at.elapsed());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+            sys.refresh_cpu();
+            sys.refresh_memory();
+            sys.refresh_components();
+            *refreshed_at = Instant::now();
+        }
+    }
+
+    /// System instance kept alive after `prewarm` so readings skip the cold start
+    static SHARED_SYSTEM: OnceLock<WarmSystem> = OnceLock::new();
+
+    /// Initializes a shared `System` on a background thread.
+    ///
+    /// The first cold reading pays for `System::new_all` plus three refreshes
+    /// (~300ms or more). Once prewarmed, `get_hardware_info` refreshes only the CPU,
+    /// memory and sensor data of the shared instance instead. The tradeoff is that
+    /// this state stays resident for the lifetime of the app, and one short-lived
+    /// thread is spawned at launch. Calling it again is a no-op.
+    pub fn prewarm() -> thread::JoinHandle<()> {
+        thread::spawn(|| {
+            SHARED_SYSTEM.get_or_init(WarmSystem::new);
+        })
+    }
+
+    /// Internal function to attempt hardware info retrieval
+    fn try_get_hardware_info() -> Result<HardwareInfo, HardwareError> {
+        // A prewarmed system already has a baseline, so a single refresh is enough
+        match SHARED_SYSTEM.get() {
+            Some(shared) => shared.read(),
+            None => read_cold(),
+        }
+    }
+
+    /// Reads hardware information from a freshly created `System`
+    pub(crate) fn read_cold() -> Result<HardwareInfo, HardwareError> {
+        let mut sys = System::new_all();
+        
+        // Refresh system information multiple times to ensure accuracy
+        for _ in 0..3 {
+            sys.refresh_all();
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        read_hardware_info(&sys)
+    }
+
+    /// Builds hardware information from an already refreshed system
+    pub(crate) fn read_hardware_info(sys: &System) -> Result<HardwareInfo, HardwareError> {
+        let cpu = read_cpu_info(sys)?;
+        let memory = read_memory_info(sys)?;
+
+        let sensors: Vec<(String, f32)> = sys.components()
+            .iter()
+            .map(|component| (component.label().to_string(), component.temperature()))
+            .collect();
+        let cpu_temperature_c = cpu_temperature_from_sensors(&sensors);
+
+        let cpu_usage = sys.global_cpu_info().cpu_usage();
+        let cpu_usage_percent = cpu_usage.is_finite().then(|| cpu_usage.clamp(0.0, 100.0));
+
+        // A cgroup limit at or above physical memory doesn't constrain anything
+        let memory_limit = read_cgroup_memory_limit().filter(|limit| *limit < memory.memory_total);
+
+        let cpu_count = cpu.cpu_count;
+        let available_cpu_cores = read_cgroup_cpu_limit().filter(|cores| *cores < cpu_count as f64);
+
+        // Get platform information with proper mapping for macOS
+        let platform = Platform::current();
+
+        let info = HardwareInfo {
+            cpu_count: cpu.cpu_count,
+            cpu_brand: cpu.cpu_brand,
+            physical_cores: cpu.physical_cores,
+            available_cpu_cores,
+            arch: cpu.arch,
+            cpu_temperature_c,
+            cpu_usage_percent,
+            cpu_frequency_mhz: sys.cpus().first().map(|cpu| cpu.frequency()).filter(|mhz| *mhz > 0),
+            cpu_max_frequency_mhz: read_cpu_max_frequency_mhz(),
+            memory_total: memory.memory_total,
+            memory_used: memory.memory_used,
+            available_memory: memory.available_memory,
+            swap_total: memory.swap_total,
+            swap_used: memory.swap_used,
+            memory_limit,
+            platform,
+            os_version: sys.long_os_version().filter(|v| !v.trim().is_empty()),
+            kernel_version: sys.kernel_version().filter(|v| !v.trim().is_empty()),
+            collected_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Nanos, true),
+        };
+
+        // Validate before returning
+        info.validate()?;
+        Ok(info)
+    }
+
+    /// CPU subset of the hardware information
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct CpuInfo {
+        #[serde(rename = "cpuCount")]
+        pub cpu_count: usize,
+        #[serde(rename = "cpuBrand")]
+        pub cpu_brand: String,
+        #[serde(rename = "physicalCores")]
+        pub physical_cores: Option<usize>,
+        pub arch: String,
+    }
+
+    /// Memory subset of the hardware information, in bytes
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct MemoryInfo {
+        #[serde(rename = "memoryTotal")]
+        pub memory_total: u64,
+        #[serde(rename = "memoryUsed")]
+        pub memory_used: u64,
+        #[serde(rename = "availableMemory")]
+        pub available_memory: u64,
+        #[serde(rename = "swapTotal")]
+        pub swap_total: u64,
+        #[serde(rename = "swapUsed")]
+        pub swap_used: u64,
+    }
+
+    /// Retrieves CPU information only.
+    ///
+    /// Core count and brand don't change between samples, so this refreshes the CPU
+    /// list once instead of the triple full refresh `get_hardware_info` performs.
+    pub fn get_cpu_info() -> Result<CpuInfo, HardwareError> {
+        let sys = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::new()));
+        read_cpu_info(&sys)
+    }
+
+    /// Retrieves memory information only.
+    ///
+    /// Memory counters are exact at the time they are read, so a single memory refresh
+    /// is enough. This is much cheaper than `get_hardware_info` for frequent polling.
+    pub fn get_memory_info() -> Result<MemoryInfo, HardwareError> {
+        let sys = System::new_with_specifics(RefreshKind::new().with_memory());
+        read_memory_info(&sys)
+    }
+
+    /// System instance that only ever refreshes memory, reused across snapshots
+    static MEMORY_SYSTEM: OnceLock<Mutex<System>> = OnceLock::new();
+
+    /// Takes a memory snapshot for frequent polling, e.g. by a memory gauge.
+    ///
+    /// Unlike `get_memory_info`, this keeps one memory-only `System` alive between
+    /// calls, so each snapshot is a single memory refresh with no allocation.
+    pub fn get_memory_snapshot() -> Result<MemoryInfo, HardwareError> {
+        let system = MEMORY_SYSTEM.get_or_init(|| {
+            Mutex::new(System::new_with_specifics(RefreshKind::new().with_memory()))
+        });
+        let mut sys = system.lock().unwrap_or_else(|e| e.into_inner());
+        sys.refresh_memory();
+        read_memory_info(&sys)
+    }
+
+    /// Reads CPU information from a system with refreshed CPU data
+    fn read_cpu_info(sys: &System) -> Result<CpuInfo, HardwareError> {
+        // Get CPU information with error handling
+        let cpu_count = sys.cpus().len();
+        if cpu_count == 0 {
+            return Err(HardwareError::CpuError("No CPU cores detected".to_string()));
+        }
+
+        let cpu_brand = sys.cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .filter(|brand| !brand.is_empty())
+            .or_else(fallback_cpu_brand)
+            .ok_or_else(|| HardwareError::CpuError("Failed to retrieve CPU information".to_string()))?;
+
+        Ok(CpuInfo {
+            cpu_count,
+            cpu_brand,
+            physical_cores: sys.physical_core_count(),
+            arch: std::env::consts::ARCH.to_string(),
+        })
+    }
+
+    /// Reads the CPU brand from the OS directly, for VMs and containers where sysinfo
+    /// reports an empty one
+    #[cfg(target_os = "macos")]
+    fn fallback_cpu_brand() -> Option<String> {
+        let output = std::process::Command::new("sysctl")
+            .args(["-n", "machdep.cpu.brand_string"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|brand| !brand.is_empty())
+    }
+
+    /// Reads the CPU brand from the OS directly, for VMs and containers where sysinfo
+    /// reports an empty one
+    #[cfg(target_os = "linux")]
+    fn fallback_cpu_brand() -> Option<String> {
+        parse_cpuinfo_model_name(&fs::read_to_string("/proc/cpuinfo").ok()?)
+    }
+
+    /// Reads the CPU brand from the OS directly, for VMs and containers where sysinfo
+    /// reports an empty one
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    fn fallback_cpu_brand() -> Option<String> {
+        None
+    }
+
+    /// Extracts the first non-empty "model name" from `/proc/cpuinfo` contents
+    pub fn parse_cpuinfo_model_name(cpuinfo: &str) -> Option<String> {
+        cpuinfo.lines()
+            .filter_map(|line| line.split_once(':'))
+            .filter(|(key, _)| key.trim() == "model name")
+            .map(|(_, value)| value.trim().to_string())
+            .find(|name| !name.is_empty())
+    }
+
+    /// Reads memory information from a system with refreshed memory data
+    fn read_memory_info(sys: &System) -> Result<MemoryInfo, HardwareError> {
+        let memory_total = sys.total_memory();
+        if memory_total == 0 {
+            return Err(HardwareError::MemoryError("Failed to detect system memory".to_string()));
+        }
+
+        Ok(MemoryInfo {
+            memory_total,
+            memory_used: sys.used_memory(),
+            available_memory: sys.available_memory(),
+            swap_total: sys.total_swap(),
+            swap_used: sys.used_swap(),
+        })
+    }
+
+    /// Stop flag of the currently running hardware monitor, if any
+    static ACTIVE_MONITOR: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+    /// Registers a new hardware monitor and returns its stop flag.
+    ///
+    /// Returns `None` if a monitor is already running, so at most one loop emits updates.
+    pub fn claim_monitor() -> Option<Arc<AtomicBool>> {
+        let mut active = ACTIVE_MONITOR.lock().unwrap_or_else(|e| e.into_inner());
+        if active.is_some() {
+            return None;
+        }
+        let stop = Arc::new(AtomicBool::new(false));
+        *active = Some(Arc::clone(&stop));
+        Some(stop)
+    }
+
+    /// Signals the running hardware monitor to stop.
+    ///
+    /// Returns whether a monitor was running. Calling it again is a no-op.
+    pub fn stop_monitor() -> bool {
+        let mut active = ACTIVE_MONITOR.lock().unwrap_or_else(|e| e.into_inner());
+        match active.take() {
+            Some(stop) => {
+                stop.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reads hardware information through `monitor` every `interval` until `stop` is set.
+    ///
+    /// Each reading is taken on the blocking thread pool, since a refresh sleeps, and
+    /// handed to `on_update`. Readings younger than `interval` are shared with other
+    /// users of the same monitor instead of being read again.
+    pub async fn run_monitor_loop<F>(
+        monitor: Arc<HardwareMonitor>,
+        stop: Arc<AtomicBool>,
+        interval: Duration,
+        mut on_update: F,
+    ) where
+        F: FnMut(Result<HardwareInfo, HardwareError>),
+    {
+        while !stop.load(Ordering::SeqCst) {
+            let reader = Arc::clone(&monitor);
+            let result = run_blocking(move || reader.get_cached(interval))
+                .await
+                .and_then(|result| result);
+
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+            on_update(result);
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Rate-limited cache around a `HardwareProvider`, `SysinfoProvider` by default.
+    ///
+    /// A full reading takes several hundred milliseconds, so UIs polling at a high rate
+    /// should go through `get_cached` and only pay for a refresh once the cached
+    /// reading is older than the age they can tolerate.
+    pub struct HardwareMonitor {
+        provider: Box<dyn HardwareProvider + Send + Sync>,
+        cached: Mutex<Option<(HardwareInfo, Instant)>>,
+    }
+
+    impl HardwareMonitor {
+        /// Creates a monitor that reads real hardware information
+        pub fn new() -> Self {
+            Self::with_provider(SysinfoProvider)
+        }
+
+        /// Creates a monitor that reads hardware information from `provider`
+        pub fn with_provider(provider: impl HardwareProvider + Send + Sync + 'static) -> Self {
+            Self {
+                provider: Box::new(provider),
+                cached: Mutex::new(None),
+            }
+        }
+
+        /// Returns the cached reading if it is younger than `max_age`, refreshing it otherwise
+        pub fn get_cached(&self, max_age: Duration) -> Result<HardwareInfo, HardwareError> {
+            let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((info, refreshed_at)) = cached.as_ref() {
+                if refreshed_at.elapsed() < max_age {
+                    return Ok(info.clone());
+                }
+            }
+
+            let info = self.provider.hardware_info()?;
+            *cached = Some((info.clone(), Instant::now()));
+            Ok(info)
+        }
+
+        /// Discards the cached reading so the next `get_cached` call reads fresh hardware
+        pub fn invalidate(&self) {
+            *self.cached.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+
+        /// Discards the cached reading and reads fresh hardware information, e.g. after
+        /// hardware was plugged in
+        pub fn refresh(&self) -> Result<HardwareInfo, HardwareError> {
+            self.invalidate();
+            self.get_cached(Duration::ZERO)
+        }
+    }
+
+    impl Default for HardwareMonitor {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Free disk space below which the disk check fails (1GB)
+    const DISK_FREE_FAIL_BYTES: u64 = 1024 * 1024 * 1024;
+    /// Free disk space below which the disk check warns (10GB)
+    const DISK_FREE_WARN_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+    /// Result of a single health check, ordered from best to worst
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum HealthStatus {
+        Pass,
+        Warn,
+        Fail,
+    }
+
+    /// One named check within a health report
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct HealthCheck {
+        pub name: String,
+        pub status: HealthStatus,
+        pub message: String,
+    }
+
+    /// Summary of every startup health check; `status` is the worst individual result
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct HealthReport {
+        pub status: HealthStatus,
+        pub checks: Vec<HealthCheck>,
+    }
+
+    impl HealthReport {
+        fn from_checks(checks: Vec<HealthCheck>) -> Self {
+            let status = checks.iter()
+                .map(|check| check.status)
+                .max()
+                .unwrap_or(HealthStatus::Pass);
+            HealthReport { status, checks }
+        }
+    }
+
+    /// Evaluates health from already collected readings.
+    ///
+    /// `free_disk_bytes` is `None` when the disk holding the application couldn't be found,
+    /// which is reported as a warning rather than a failure.
+    pub fn evaluate_health(
+        info: &HardwareInfo,
+        reqs: &SystemRequirements,
+        free_disk_bytes: Option<u64>,
+    ) -> HealthReport {
+        let compatibility = info.check_all(reqs);
+        let compatibility_check = if compatibility.passed {
+            HealthCheck {
+                name: "compatibility".to_string(),
+                status: HealthStatus::Pass,
+                message: "System meets the minimum requirements".to_string(),
+            }
+        } else {
+            HealthCheck {
+                name: "compatibility".to_string(),
+                status: HealthStatus::Fail,
+                message: compatibility.failures.join("; "),
+            }
+        };
+
+        let (status, message) = match free_disk_bytes {
+            Some(free) if free < DISK_FREE_FAIL_BYTES => (HealthStatus::Fail,
+                format!("Only {} of disk space free", MemoryDisplay::from_bytes(free).format_human())),
+            Some(free) if free < DISK_FREE_WARN_BYTES => (HealthStatus::Warn,
+                format!("Low disk space: {} free", MemoryDisplay::from_bytes(free).format_human())),
+            Some(free) => (HealthStatus::Pass,
+                format!("{} of disk space free", MemoryDisplay::from_bytes(free).format_human())),
+            None => (HealthStatus::Warn, "Could not determine free disk space".to_string()),
+        };
+        let disk_check = HealthCheck { name: "disk_space".to_string(), status, message };
+
+        let (status, message) = match info.memory_pressure() {
+            MemoryPressure::Low => (HealthStatus::Pass, "Memory pressure is low"),
+            MemoryPressure::Medium => (HealthStatus::Warn, "Memory pressure is elevated"),
+            MemoryPressure::High => (HealthStatus::Fail, "Memory pressure is high"),
+        };
+        let memory_check = HealthCheck {
+            name: "memory_pressure".to_string(),
+            status,
+            message: message.to_string(),
+        };
+
+        HealthReport::from_checks(vec![compatibility_check, disk_check, memory_check])
+    }
+
+    /// Free space on the disk holding `dir`, matched by the longest mount point prefix
+    fn free_disk_space(dir: &Path) -> Option<u64> {
+        let mut sys = System::new();
+        sys.refresh_disks_list();
+        sys.disks()
+            .iter()
+            .filter(|disk| dir.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len())
+            .map(|disk| disk.available_space())
+    }
+
+    /// Runs every startup health check in one call, checking free space on the disk
+    /// that holds `data_dir` (the app data or model directory)
+    pub fn system_health(data_dir: &Path) -> HealthReport {
+        match get_hardware_info() {
+            Ok(info) => evaluate_health(&info, &SystemRequirements::default(), free_disk_space(data_dir)),
+            Err(e) => HealthReport::from_checks(vec![HealthCheck {
+                name: "hardware".to_string(),
+                status: HealthStatus::Fail,
+                message: e.to_string(),
+            }]),
+        }
+    }
+
+    /// One mounted disk in a diagnostics bundle, sizes in bytes
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct DiskSummary {
+        pub name: String,
+        #[serde(rename = "mountPoint")]
+        pub mount_point: String,
+        #[serde(rename = "fileSystem")]
+        pub file_system: String,
+        #[serde(rename = "totalSpace")]
+        pub total_space: u64,
+        #[serde(rename = "availableSpace")]
+        pub available_space: u64,
+    }
+
+    /// One network interface in a diagnostics bundle, with byte totals since boot
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct NetworkInterfaceSummary {
+        pub name: String,
+        #[serde(rename = "totalReceived")]
+        pub total_received: u64,
+        #[serde(rename = "totalTransmitted")]
+        pub total_transmitted: u64,
+    }
+
+    /// Everything support needs from a bug report, serialized by `export_diagnostics`
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct DiagnosticsBundle {
+        /// RFC 3339 UTC time the bundle was collected
+        pub timestamp: String,
+        #[serde(rename = "appVersion")]
+        pub app_version: String,
+        /// Includes the OS and kernel versions
+        pub hardware: HardwareInfo,
+        pub disks: Vec<DiskSummary>,
+        #[serde(rename = "networkInterfaces")]
+        pub network_interfaces: Vec<NetworkInterfaceSummary>,
+    }
+
+    /// Collects hardware, disks and network interfaces into a pretty-printed JSON
+    /// document suitable for attaching to bug reports
+    pub fn export_diagnostics() -> Result<String, HardwareError> {
+        let hardware = get_hardware_info()?;
+
+        let mut sys = System::new();
+        sys.refresh_disks_list();
+        sys.refresh_networks_list();
+
+        let disks = sys.disks()
+            .iter()
+            .map(|disk| DiskSummary {
+                name: disk.name().to_string_lossy().into_owned(),
+                mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                file_system: String::from_utf8_lossy(disk.file_system()).into_owned(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+            })
+            .collect();
+
+        let mut network_interfaces: Vec<NetworkInterfaceSummary> = sys.networks()
+            .iter()
+            .map(|(name, data)| NetworkInterfaceSummary {
+                name: name.clone(),
+                total_received: data.total_received(),
+                total_transmitted: data.total_transmitted(),
+            })
+            .collect();
+        network_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let bundle = DiagnosticsBundle {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            hardware,
+            disks,
+            network_interfaces,
+        };
+        serde_json::to_string_pretty(&bundle)
+            .map_err(|e| HardwareError::SystemError(format!("Failed to serialize diagnostics: {}", e)))
+    }
+
+    /// Source of hardware readings, so code that depends on them can be tested
+    /// with fabricated values
+    pub trait HardwareProvider {
+        fn hardware_info(&self) -> Result<HardwareInfo, HardwareError>;
+    }
+
+    /// Reads real hardware through sysinfo
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct SysinfoProvider;
+
+    impl HardwareProvider for SysinfoProvider {
+        fn hardware_info(&self) -> Result<HardwareInfo, HardwareError> {
+            get_hardware_info()
+        }
+    }
+
+    /// Returns the same preset reading every time
+    #[derive(Debug, Clone)]
+    pub struct StaticProvider {
+        info: HardwareInfo,
+    }
+
+    impl StaticProvider {
+        pub fn new(info: HardwareInfo) -> Self {
+            Self { info }
+        }
+    }
+
+    impl HardwareProvider for StaticProvider {
+        fn hardware_info(&self) -> Result<HardwareInfo, HardwareError> {
+            Ok(self.info.clone())
+        }
+    }
+
+    /// Checks if the system is compatible with the application
+    pub fn check_system_compatibility() -> Result<(), HardwareError> {
+        check_system_compatibility_detailed().map(|_| ())
+    }
+
+    /// Checks compatibility and returns the inspected hardware on success, so callers
+    /// don't need a second `get_hardware_info` call
+    pub fn check_system_compatibility_detailed() -> Result<HardwareInfo, HardwareError> {
+        check_system_compatibility_with(&SysinfoProvider)
+    }
+
+    /// Checks the provider's hardware against the default requirements, returning the
+    /// inspected hardware on success
+    pub fn check_system_compatibility_with(provider: &dyn HardwareProvider) -> Result<HardwareInfo, HardwareError> {
+        let info = provider.hardware_info()?;
+        info.meets_requirements(&SystemRequirements::default())?;
+        Ok(info)
+    }
+}
+
+/// Application logging
+///
+/// Configures `tracing` to write structured events both to stdout and to a fixed
+/// `app.log` file, replacing the previous hand-rolled file logging. The level
+/// defaults to INFO and can be overridden with `RUST_LOG`.
+pub mod logging {
+    use std::path::Path;
+    use std::sync::OnceLock;
+    use tracing_appender::non_blocking::WorkerGuard;
+    use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+    use tracing_subscriber::fmt;
+    use tracing_subscriber::prelude::*;
+
+    /// Name of the log file, unchanged from the previous file logging so existing
+    /// workflows that read it keep working
+    pub const LOG_FILE_NAME: &str = "app.log";
+
+    /// Keeps the background file writer alive so buffered events are flushed
+    static FILE_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+
+    /// Initializes logging with log files in the current working directory
+    pub fn init_logging() {
+        init_logging_in(Path::new("."));
+    }
+
+    /// Initializes logging with log files in `log_dir`.
+    ///
+    /// Only the first call has any effect, so it is safe to call from several
+    /// entry points. If another global subscriber is already installed, it is kept.
+    pub fn init_logging_in(log_dir: &Path) {
+        FILE_GUARD.get_or_init(|| {
+            let appender = tracing_appender::rolling::never(log_dir, LOG_FILE_NAME);
+            let (file_writer, guard) = tracing_appender::non_blocking(appender);
+
+            let _ = tracing_subscriber::registry()
+                .with(EnvFilter::builder()
+                    .with_default_directive(LevelFilter::INFO.into())
+                    .from_env_lossy())
+                .with(fmt::layer().with_writer(std::io::stdout))
+                .with(fmt::layer().with_ansi(false).with_writer(file_writer))
+                .try_init();
+
+            guard
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hardware::{self, Bytes, HardwareInfo, HardwareError, HealthStatus, Kib, Mib, MemoryDisplay, MemoryPressure, Platform, SystemRequirements};
+    use super::logging;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use sysinfo::{System, SystemExt};
+
+    /// Creates an empty scratch directory unique to this test process
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("homewiseai-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("Failed to create temp dir");
+        dir
+    }
+
+    /// Valid, compatible reading that test cases override field by field
+    fn sample_info() -> HardwareInfo {
+        HardwareInfo {
+            cpu_count: 4,
+            cpu_brand: "Test CPU".to_string(),
+            physical_cores: None,
+            available_cpu_cores: None,
+            arch: "x86_64".to_string(),
+            cpu_temperature_c: None,
+            cpu_usage_percent: None,
+            cpu_frequency_mhz: None,
+            cpu_max_frequency_mhz: None,
+            memory_total: 16 * 1024 * 1024 * 1024,
+            memory_used: 4 * 1024 * 1024 * 1024,
+            available_memory: 12 * 1024 * 1024 * 1024,
+            swap_total: 0,
+            swap_used: 0,
+            memory_limit: None,
+            platform: Platform::Linux,
+            os_version: None,
+            kernel_version: None,
+            collected_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_hardware_info_basic() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        assert!(info.cpu_count > 0, "System should have at least one CPU core");
+        assert!(!info.cpu_brand.is_empty(), "CPU brand should not be empty");
+        assert!(info.memory_total > 0, "Total memory should be greater than 0");
+        assert!(info.memory_used <= info.memory_total, "Used memory should not exceed total memory");
+    }
+
+    #[test]
+    fn test_system_compatibility() {
+        let info = hardware::check_system_compatibility_detailed().expect("System should meet minimum requirements");
+        assert!(info.cpu_count >= SystemRequirements::default().min_cpu_cores, "Returned info should reflect the checked hardware");
+    }
+
+    #[test]
+    fn test_custom_requirements() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        let reqs = SystemRequirements::builder()
+            .min_cpu_cores(info.cpu_count + 1) // Impossible requirement
+            .min_memory(Kib(1024))
+            .supported_platforms(&[Platform::Windows, Platform::MacOs])
+            .build();
+        let result = info.meets_requirements(&reqs);
+        assert!(result.is_err(), "Should fail with impossible CPU requirement");
+    }
+
+    #[test]
+    fn test_hardware_info_memory_consistency() {
+        let info1 = hardware::get_hardware_info().expect("Should get first hardware info");
+        thread::sleep(Duration::from_millis(100));
+        let info2 = hardware::get_hardware_info().expect("Should get second hardware info");
+
+        assert_eq!(info1.memory_total, info2.memory_total, "Total memory should remain constant");
+        assert!(info1.cpu_count == info2.cpu_count, "CPU count should remain constant");
+    }
+
+    #[test]
+    fn test_hardware_info_serialization() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        let serialized = serde_json::to_string(&info).expect("Failed to serialize HardwareInfo");
+        let deserialized: hardware::HardwareInfo = serde_json::from_str(&serialized).expect("Failed to deserialize HardwareInfo");
+        
+        assert_eq!(info, deserialized, "Serialization/deserialization should preserve data");
+    }
+
+    #[test]
+    fn test_memory_values_sanity() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        // Most modern systems have at least 1GB of RAM
+        assert!(Bytes(info.memory_total) >= Bytes::from(Mib(1024)), "Total memory should be at least 1GB");
+        // Used memory should be non-zero on a running system
+        assert!(info.memory_used > 0, "Used memory should be greater than 0");
+        // Used memory should not exceed total memory
+        assert!(info.memory_used <= info.memory_total, "Used memory should not exceed total memory");
+    }
+
+    #[test]
+    fn test_hardware_info_validation() {
+        // Test invalid CPU count
+        let invalid_cpu = HardwareInfo {
+            cpu_count: 0,
+            ..sample_info()
+        };
+        assert!(invalid_cpu.validate().is_err(), "Should fail with zero CPU count");
+
+        // Test invalid CPU brand
+        let invalid_brand = HardwareInfo {
+            cpu_brand: "".to_string(),
+            ..sample_info()
+        };
+        assert!(invalid_brand.validate().is_err(), "Should fail with empty CPU brand");
+
+        // Test invalid memory total
+        let invalid_memory = HardwareInfo {
+            memory_total: 0,
+            memory_used: 0,
+            available_memory: 0,
+            ..sample_info()
+        };
+        assert!(invalid_memory.validate().is_err(), "Should fail with zero total memory");
+
+        // Test invalid memory usage
+        let invalid_usage = HardwareInfo {
+            memory_total: 1024,
+            memory_used: 2048,
+            available_memory: 0,
+            ..sample_info()
+        };
+        assert!(invalid_usage.validate().is_err(), "Should fail when used memory exceeds total");
+
+        // Test invalid swap usage
+        let invalid_swap = HardwareInfo {
+            swap_total: 1024,
+            swap_used: 2048,
+            ..sample_info()
+        };
+        assert!(invalid_swap.validate().is_err(), "Should fail when used swap exceeds total");
+    }
+
+    #[test]
+    fn test_cgroup_memory_limit_v2() {
+        let limited = temp_dir("cgroup-v2-limited");
+        fs::write(limited.join("memory.max"), "536870912\n").unwrap();
+        assert_eq!(hardware::read_cgroup_memory_limit_from(&limited), Some(536870912));
+
+        let unlimited = temp_dir("cgroup-v2-unlimited");
+        fs::write(unlimited.join("memory.max"), "max\n").unwrap();
+        assert_eq!(hardware::read_cgroup_memory_limit_from(&unlimited), None, "\"max\" means no limit");
+    }
+
+    #[test]
+    fn test_cgroup_memory_limit_v1() {
+        let limited = temp_dir("cgroup-v1-limited");
+        fs::create_dir_all(limited.join("memory")).unwrap();
+        fs::write(limited.join("memory").join("memory.limit_in_bytes"), "1073741824\n").unwrap();
+        assert_eq!(hardware::read_cgroup_memory_limit_from(&limited), Some(1073741824));
+
+        let unlimited = temp_dir("cgroup-v1-unlimited");
+        fs::create_dir_all(unlimited.join("memory")).unwrap();
+        fs::write(unlimited.join("memory").join("memory.limit_in_bytes"), "9223372036854771712\n").unwrap();
+        assert_eq!(hardware::read_cgroup_memory_limit_from(&unlimited), None, "v1 sentinel means no limit");
+
+        let missing = temp_dir("cgroup-missing");
+        assert_eq!(hardware::read_cgroup_memory_limit_from(&missing), None, "No cgroup files means no limit");
+    }
+
+    #[test]
+    fn test_requirements_use_cgroup_memory_limit() {
+        let mut info = sample_info();
+        assert!(info.meets_requirements(&SystemRequirements::default()).is_ok(), "Unlimited container should pass");
+
+        info.memory_limit = Some(1024 * 1024 * 1024);
+        assert_eq!(info.effective_memory_total(), 1024 * 1024 * 1024);
+        assert!(info.meets_requirements(&SystemRequirements::default()).is_err(), "Limited container should fail");
+    }
+
+    #[test]
+    fn test_cpu_topology() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        if let Some(physical_cores) = info.physical_cores {
+            assert!(physical_cores > 0, "Physical core count should be positive when reported");
+            assert!(physical_cores <= info.cpu_count, "Physical cores should not exceed logical cores");
+        }
+        assert!(!info.arch.is_empty(), "Architecture should not be empty");
+    }
+
+    #[test]
+    fn test_single_monitor_claim() {
+        let stop = hardware::claim_monitor().expect("Should claim the first monitor");
+        assert!(hardware::claim_monitor().is_none(), "Only one monitor should run at a time");
+
+        assert!(hardware::stop_monitor(), "Stopping should report the running monitor");
+        assert!(stop.load(Ordering::SeqCst), "Stop flag should be set");
+        assert!(!hardware::stop_monitor(), "Stopping again should be a no-op");
+
+        assert!(hardware::claim_monitor().is_some(), "A new monitor can start after stopping");
+        hardware::stop_monitor();
+    }
+
+    #[tokio::test]
+    async fn test_monitor_loop_stops_when_signalled() {
+        let stop = Arc::new(AtomicBool::new(false));
+        let (monitor, provider_readings) = counting_monitor();
+        let readings = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&readings);
+        let handle = tokio::spawn(hardware::run_monitor_loop(
+            Arc::new(monitor),
+            Arc::clone(&stop),
+            Duration::from_millis(10),
+            move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            },
+        ));
+
+        while readings.load(Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        stop.store(true, Ordering::SeqCst);
+
+        tokio::time::timeout(Duration::from_secs(10), handle)
+            .await
+            .expect("Monitor loop should stop when signalled")
+            .expect("Monitor task should not panic");
+        assert!(provider_readings.load(Ordering::SeqCst) > 0, "Readings should come from the monitor's provider");
+    }
+
+    #[test]
+    fn test_hardware_error_as_std_error() {
+        let error: Box<dyn std::error::Error> = Box::new(HardwareError::MemoryError("Out of memory".to_string()));
+        assert_eq!(error.to_string(), "Memory Error: Out of memory");
+
+        let serialized = serde_json::to_string(&HardwareError::CpuError("No cores".to_string()))
+            .expect("Failed to serialize HardwareError");
+        assert_eq!(serialized, r#"{"CpuError":"No cores"}"#, "JSON shape should be unchanged");
+    }
+
+    #[test]
+    fn test_battery_info_never_errors_without_battery() {
+        let battery = hardware::get_battery_info().expect("Battery detection should not fail");
+        if let Some(battery) = battery {
+            if let Some(charge) = battery.charge_percent {
+                assert!((0.0..=100.0).contains(&charge), "Charge should be a percentage");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpu_temperature_range() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        if let Some(temp) = info.cpu_temperature_c {
+            assert!((0.0..=125.0).contains(&temp), "CPU temperature should be plausible, got {}", temp);
+        }
+    }
+
+    #[test]
+    fn test_cpu_temperature_sensor_selection() {
+        let sensors = vec![
+            ("acpitz temp1".to_string(), 30.0),
+            ("coretemp Core 0".to_string(), 50.0),
+            ("coretemp Core 1".to_string(), 54.0),
+        ];
+        assert_eq!(hardware::cpu_temperature_from_sensors(&sensors), Some(52.0), "Should average core sensors");
+
+        let mut with_package = sensors.clone();
+        with_package.push(("coretemp Package id 0".to_string(), 60.0));
+        assert_eq!(hardware::cpu_temperature_from_sensors(&with_package), Some(60.0), "Should prefer the package sensor");
+
+        let unusable = vec![("nvme Composite".to_string(), 40.0), ("CPU".to_string(), f32::NAN)];
+        assert_eq!(hardware::cpu_temperature_from_sensors(&unusable), None);
+    }
+
+    #[test]
+    fn test_memory_display_conversions() {
+        assert_eq!(MemoryDisplay::from_kib(1024 * 1024).as_gib(), 1.0, "1 GiB in KiB");
+        assert_eq!(MemoryDisplay::from_bytes(16 * 1024 * 1024 * 1024).as_gib(), 16.0);
+        assert_eq!(MemoryDisplay::from_kib(512 * 1024).as_gib(), 0.5);
+        assert_eq!(MemoryDisplay::from_kib(3).bytes(), 3072);
+
+        assert_eq!(MemoryDisplay::from_bytes(512).format_human(), "512 B");
+        assert_eq!(MemoryDisplay::from_bytes(1024).format_human(), "1.0 KiB");
+        assert_eq!(MemoryDisplay::from_kib(1023 * 1024).format_human(), "1023.0 MiB");
+        assert_eq!(MemoryDisplay::from_bytes(1024 * 1024 - 10).format_human(), "1.0 MiB", "Rounds up into MiB");
+        assert_eq!(MemoryDisplay::from_kib(1024 * 1024 - 10).format_human(), "1.0 GiB", "Rounds up into GiB");
+        assert_eq!(MemoryDisplay::from_kib(1024 * 1024 * 1024 - 10).format_human(), "1.0 TiB", "Rounds up into TiB");
+        assert_eq!(MemoryDisplay::from_kib(1024 * 1024 - 103).format_human(), "1023.9 MiB", "Stays below the boundary");
+        assert_eq!(MemoryDisplay::from_kib(1024 * 1024).format_human(), "1.0 GiB");
+        assert_eq!(MemoryDisplay::from_bytes(16_750_372_454).format_human(), "15.6 GiB");
+        assert_eq!(MemoryDisplay::from_bytes(2 * 1024 * 1024 * 1024 * 1024).format_human(), "2.0 TiB");
+    }
+
+    #[test]
+    fn test_os_details_never_empty() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        assert_ne!(info.os_version.as_deref(), Some(""), "OS version should be None rather than empty");
+        assert_ne!(info.kernel_version.as_deref(), Some(""), "Kernel version should be None rather than empty");
+    }
+
+    #[test]
+    fn test_uptime_non_decreasing() {
+        let first = hardware::get_uptime().expect("Should get uptime");
+        thread::sleep(Duration::from_millis(100));
+        let second = hardware::get_uptime().expect("Should get uptime");
+
+        assert!(second.uptime_seconds >= first.uptime_seconds, "Uptime should never go backwards");
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(&first.boot_time).is_ok(),
+            "Boot time should be RFC 3339"
+        );
+    }
+
+    /// Fixture provider whose used memory is the number of readings taken so far
+    struct CountingProvider {
+        readings: Arc<AtomicUsize>,
+    }
+
+    impl hardware::HardwareProvider for CountingProvider {
+        fn hardware_info(&self) -> Result<HardwareInfo, HardwareError> {
+            let reading = self.readings.fetch_add(1, Ordering::SeqCst) as u64 + 1;
+            Ok(HardwareInfo {
+                memory_total: 1024,
+                memory_used: reading,
+                available_memory: 0,
+                ..sample_info()
+            })
+        }
+    }
+
+    fn counting_monitor() -> (hardware::HardwareMonitor, Arc<AtomicUsize>) {
+        let readings = Arc::new(AtomicUsize::new(0));
+        let provider = CountingProvider { readings: Arc::clone(&readings) };
+        (hardware::HardwareMonitor::with_provider(provider), readings)
+    }
+
+    #[test]
+    fn test_hardware_monitor_cache() {
+        let (monitor, readings) = counting_monitor();
+
+        let first = monitor.get_cached(Duration::from_secs(60)).expect("Should get hardware info");
+        assert_eq!(readings.load(Ordering::SeqCst), 1);
+
+        let second = monitor.get_cached(Duration::from_secs(60)).expect("Should get cached info");
+        assert_eq!(readings.load(Ordering::SeqCst), 1, "Fresh cache should not refresh");
+        assert_eq!(first, second, "Cached read should return the same snapshot");
+
+        monitor.get_cached(Duration::ZERO).expect("Should refresh hardware info");
+        assert_eq!(readings.load(Ordering::SeqCst), 2, "Stale cache should refresh");
+
+        let fixed = hardware::HardwareMonitor::with_provider(hardware::StaticProvider::new(sample_info()));
+        assert_eq!(fixed.get_cached(Duration::ZERO).unwrap(), sample_info());
+    }
+
+    #[test]
+    fn test_load_average() {
+        let load = hardware::get_load_average();
+        if cfg!(target_os = "windows") {
+            assert!(load.is_none(), "Windows should not report a load average");
+        } else {
+            let load = load.expect("Unix systems should report a load average");
+            assert!(load.one >= 0.0 && load.five >= 0.0 && load.fifteen >= 0.0, "Load averages should be non-negative");
+        }
+    }
+
+    #[test]
+    fn test_top_processes() {
+        let processes = hardware::get_top_processes(5);
+        assert!(!processes.is_empty(), "At least the test process should be listed");
+        assert!(processes.len() <= 5, "Should return at most n processes");
+        assert!(
+            processes.windows(2).all(|pair| pair[0].memory_kb >= pair[1].memory_kb),
+            "Processes should be sorted by memory, descending"
+        );
+        assert!(hardware::get_top_processes(0).is_empty(), "n = 0 should return nothing");
+    }
+
+    #[test]
+    fn test_cpu_features() {
+        let features = hardware::get_cpu_features();
+        if cfg!(target_arch = "aarch64") {
+            assert!(features.neon, "NEON is mandatory on aarch64");
+        } else {
+            assert!(!features.neon, "NEON only exists on ARM");
+        }
+    }
+
+    #[test]
+    fn test_prewarm_speeds_up_readings() {
+        // Uses its own warm system so the global one stays uninitialized for other tests
+        let start = Instant::now();
+        hardware::read_cold().expect("Should get hardware info");
+        let cold = start.elapsed();
+
+        let warm_system = hardware::WarmSystem::new();
+        // Prewarming happens at launch, well before the first reading
+        thread::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL);
+
+        let start = Instant::now();
+        warm_system.read().expect("Should get hardware info after prewarm");
+        let warm = start.elapsed();
+        assert!(warm < cold, "Prewarmed reading ({:?}) should be faster than cold ({:?})", warm, cold);
+
+        let start = Instant::now();
+        let info = warm_system.read().expect("Should get back-to-back hardware info");
+        assert!(
+            start.elapsed() >= System::MINIMUM_CPU_UPDATE_INTERVAL / 2,
+            "Back-to-back readings should wait for a meaningful CPU sample"
+        );
+        if let Some(usage) = info.cpu_usage_percent {
+            assert!((0.0..=100.0).contains(&usage), "CPU usage out of range: {}", usage);
+        }
+    }
+
+    #[test]
+    fn test_platform_normalization() {
+        assert_eq!(Platform::from_os_str("darwin"), Platform::MacOs);
+        assert_eq!(Platform::from_os_str("macos"), Platform::MacOs);
+        assert_eq!(Platform::from_os_str("Windows"), Platform::Windows);
+        assert_eq!(Platform::from_os_str("linux"), Platform::Linux);
+        assert_eq!(Platform::from_os_str("haiku"), Platform::Other("haiku".to_string()));
+        assert_eq!(Platform::from_os_str("ios"), Platform::Ios);
+        assert_eq!(Platform::from_os_str("iPhoneOS"), Platform::Ios);
+        assert_eq!(Platform::from_os_str("android"), Platform::Android);
+        assert_eq!(Platform::from_os_str("FreeBSD"), Platform::FreeBsd);
+    }
+
+    #[test]
+    fn test_platform_mapping_is_shared() {
+        for (raw, expected) in [
+            ("darwin", Platform::MacOs),
+            ("ios", Platform::Ios),
+            ("android", Platform::Android),
+            ("freebsd", Platform::FreeBsd),
+            ("haiku", Platform::Other("haiku".to_string())),
+        ] {
+            assert_eq!(Platform::from_os_str(raw), expected, "Detection mapping for {}", raw);
+            let deserialized: Platform = serde_json::from_value(serde_json::json!(raw)).expect("Should deserialize platform");
+            assert_eq!(deserialized, expected, "Requirements mapping for {}", raw);
+            assert_eq!(Platform::from_os_str(expected.as_str()), expected, "Canonical name should round-trip");
+        }
+    }
+
+    #[test]
+    fn test_platform_serde_compatibility() {
+        assert_eq!(serde_json::to_string(&Platform::MacOs).unwrap(), r#""macos""#);
+        assert_eq!(serde_json::to_string(&Platform::Windows).unwrap(), r#""windows""#);
+        assert_eq!(serde_json::to_string(&Platform::Other("haiku".to_string())).unwrap(), r#""haiku""#);
+
+        let darwin: Platform = serde_json::from_str(r#""darwin""#).expect("Should deserialize legacy name");
+        assert_eq!(darwin, Platform::MacOs);
+        let linux: Platform = serde_json::from_str(r#""linux""#).expect("Should deserialize platform");
+        assert_eq!(linux, Platform::Linux);
+    }
+
+    #[test]
+    fn test_compatibility_report_lists_all_failures() {
+        let info = HardwareInfo {
+            cpu_count: 1,
+            memory_total: 1024,
+            memory_used: 512,
+            available_memory: 0,
+            platform: Platform::Other("haiku".to_string()),
+            ..sample_info()
+        };
+
+        let report = info.check_all(&SystemRequirements::default());
+        assert!(!report.passed, "Report should fail");
+        assert_eq!(report.failures.len(), 3, "Every unmet requirement should be reported");
+        assert!(report.failures[0].contains("Insufficient CPU cores"));
+        assert!(report.failures[1].contains("Insufficient memory"));
+        assert!(report.failures[2].contains("Unsupported platform: haiku"));
+
+        match info.meets_requirements(&SystemRequirements::default()) {
+            Err(HardwareError::CompatibilityError(msg)) => assert_eq!(msg, report.failures[0], "Should report the first failure"),
+            other => panic!("Expected a compatibility error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_init_logging_is_idempotent() {
+        let log_dir = temp_dir("logging");
+        logging::init_logging_in(&log_dir);
+        assert!(log_dir.join(logging::LOG_FILE_NAME).is_file(), "Logs should go to a fixed app.log");
+        logging::init_logging_in(&log_dir);
+        logging::init_logging();
+        tracing::info!("Logging initialized");
+    }
+
+    #[test]
+    fn test_memory_pressure_bands() {
+        let mut info = HardwareInfo {
+            memory_total: 1000,
+            memory_used: 500,
+            available_memory: 500,
+            ..sample_info()
+        };
+        assert_eq!(info.memory_pressure(), MemoryPressure::Low);
+
+        info.available_memory = 250;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Low, "25% available is still low pressure");
+
+        info.available_memory = 150;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Medium);
+
+        info.available_memory = 100;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Medium, "10% available is still medium pressure");
+
+        info.available_memory = 50;
+        assert_eq!(info.memory_pressure(), MemoryPressure::High);
+
+        info.available_memory = 2000;
+        assert!(info.validate().is_err(), "Available memory should not exceed total");
+    }
+
+    #[test]
+    fn test_memory_pressure_respects_memory_limit() {
+        // Host RAM is plentiful but the container is capped at 1000 bytes.
+        let mut info = HardwareInfo {
+            memory_total: 16_000,
+            memory_used: 4_000,
+            available_memory: 12_000,
+            memory_limit: Some(1_000),
+            ..sample_info()
+        };
+        assert_eq!(info.memory_pressure(), MemoryPressure::Low, "Available memory is capped at the limit");
+
+        info.available_memory = 250;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Low);
+
+        info.available_memory = 150;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Medium);
+
+        info.available_memory = 100;
+        assert_eq!(info.memory_pressure(), MemoryPressure::Medium);
+
+        info.available_memory = 50;
+        assert_eq!(info.memory_pressure(), MemoryPressure::High);
+    }
+
+    #[cfg(feature = "ts")]
+    #[test]
+    fn export_bindings() {
+        use ts_rs::TS;
+
+        let out_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("bindings");
+        HardwareInfo::export_all_to(&out_dir).expect("Failed to export HardwareInfo bindings");
+        HardwareError::export_all_to(&out_dir).expect("Failed to export HardwareError bindings");
+
+        let generated = fs::read_to_string(out_dir.join("HardwareInfo.ts")).expect("Bindings should be written");
+        for field in ["cpuCount", "cpuBrand", "memoryTotal", "memoryUsed", "swapTotal", "platform: string"] {
+            assert!(generated.contains(field), "Generated bindings should contain {}", field);
+        }
+    }
+
+    #[test]
+    fn test_container_detection_from_files() {
+        let docker = temp_dir("virt-dockerenv");
+        fs::write(docker.join(".dockerenv"), "").unwrap();
+        assert!(hardware::detect_virtualization_in(&docker).is_container, ".dockerenv marks a container");
+
+        let kubernetes = temp_dir("virt-cgroup");
+        fs::create_dir_all(kubernetes.join("proc/1")).unwrap();
+        fs::write(
+            kubernetes.join("proc/1/cgroup"),
+            "12:memory:/kubepods/burstable/pod1234/abcd\n11:cpu:/kubepods/burstable/pod1234/abcd\n",
+        ).unwrap();
+        assert!(hardware::detect_virtualization_in(&kubernetes).is_container, "kubepods cgroup marks a container");
+
+        let host = temp_dir("virt-host");
+        fs::create_dir_all(host.join("proc/1")).unwrap();
+        fs::write(host.join("proc/1/cgroup"), "0::/init.scope\n").unwrap();
+        assert!(!hardware::detect_virtualization_in(&host).is_container, "Host init cgroup is not a container");
+    }
+
+    #[test]
+    fn test_wsl_detection_from_files() {
+        let wsl = temp_dir("virt-wsl");
+        fs::create_dir_all(wsl.join("proc/sys/kernel")).unwrap();
+        fs::write(wsl.join("proc/sys/kernel/osrelease"), "5.15.153.1-microsoft-standard-WSL2\n").unwrap();
+
+        let info = hardware::detect_virtualization_in(&wsl);
+        assert!(info.is_virtual, "WSL runs in a VM");
+        assert_eq!(info.hypervisor.as_deref(), Some("WSL"));
+        assert!(!info.is_container);
+    }
+
+    #[test]
+    fn test_partial_refresh_paths() {
+        let cpu = hardware::get_cpu_info().expect("Should get CPU info");
+        assert!(cpu.cpu_count > 0 && !cpu.cpu_brand.is_empty());
+
+        let memory = hardware::get_memory_info().expect("Should get memory info");
+        assert!(memory.memory_total > 0);
+        assert!(memory.memory_used <= memory.memory_total);
+    }
+
+    #[test]
+    fn test_health_report_statuses() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        let healthy = sample_info();
+        let reqs = SystemRequirements::default();
+
+        let pass = hardware::evaluate_health(&healthy, &reqs, Some(100 * GIB));
+        assert_eq!(pass.status, HealthStatus::Pass);
+        assert_eq!(pass.checks.len(), 3);
+
+        let warn = hardware::evaluate_health(&healthy, &reqs, Some(5 * GIB));
+        assert_eq!(warn.status, HealthStatus::Warn);
+        assert!(warn.checks.iter().any(|c| c.name == "disk_space" && c.status == HealthStatus::Warn));
+
+        let starved = HardwareInfo { available_memory: GIB / 2, ..healthy.clone() };
+        let fail = hardware::evaluate_health(&starved, &reqs, Some(100 * GIB));
+        assert_eq!(fail.status, HealthStatus::Fail);
+        assert!(fail.checks.iter().any(|c| c.name == "memory_pressure" && c.status == HealthStatus::Fail));
+
+        let incompatible = HardwareInfo { cpu_count: 1, ..healthy };
+        let fail = hardware::evaluate_health(&incompatible, &reqs, None);
+        assert_eq!(fail.status, HealthStatus::Fail);
+        assert!(fail.checks[0].message.contains("Insufficient CPU cores"));
+    }
+
+    #[test]
+    fn test_memory_unit_conversions() {
+        assert_eq!(Bytes::from(Kib(1)), Bytes(1024));
+        assert_eq!(Bytes::from(Mib(1)), Bytes(1024 * 1024));
+        assert_eq!(Kib::from(Mib(1)), Kib(1024));
+        assert_eq!(Kib::from(Mib(4 * 1024)), SystemRequirements::default().min_memory(), "Default requirement is 4 GiB");
+
+        // Narrowing conversions round down at each boundary
+        assert_eq!(Bytes(1023).to_kib(), Kib(0));
+        assert_eq!(Bytes(1024).to_kib(), Kib(1));
+        assert_eq!(Bytes(1024 * 1024 - 1).to_mib(), Mib(0));
+        assert_eq!(Bytes(1024 * 1024).to_mib(), Mib(1));
+        assert_eq!(Kib(1023).to_mib(), Mib(0));
+        assert_eq!(Kib(1024).to_mib(), Mib(1));
+
+        // Widening conversions saturate instead of overflowing
+        assert_eq!(Bytes::from(Kib(u64::MAX)), Bytes(u64::MAX));
+        assert_eq!(Bytes::from(Mib(u64::MAX / 1024)), Bytes(u64::MAX));
+        assert_eq!(Kib::from(Mib(u64::MAX)), Kib(u64::MAX));
+    }
+
+    #[test]
+    fn test_memory_requirement_compares_kib_against_bytes() {
+        let mut info = HardwareInfo {
+            memory_total: Bytes::from(Mib(4 * 1024)).0,
+            memory_used: 0,
+            available_memory: 0,
+            ..sample_info()
+        };
+        assert!(info.meets_requirements(&SystemRequirements::default()).is_ok(), "Exactly 4 GiB should pass");
+
+        // Comparing the raw KiB number against bytes would have let this pass
+        info.memory_total -= 1;
+        match info.meets_requirements(&SystemRequirements::default()) {
+            Err(HardwareError::CompatibilityError(msg)) => {
+                assert_eq!(msg, "Insufficient memory. Required: 4194304 KB, Available: 4194303 KB");
+            }
+            other => panic!("Expected a compatibility error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cpu_usage_percent_range() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        if let Some(usage) = info.cpu_usage_percent {
+            assert!((0.0..=100.0).contains(&usage), "CPU usage out of range: {}", usage);
+        }
+    }
+
+    #[test]
+    fn test_requirements_platforms() {
+        let reqs = SystemRequirements::new(2, Kib(1024), &[Platform::MacOs, Platform::Linux]);
+        assert!(reqs.supports(&Platform::MacOs));
+        assert!(!reqs.supports(&Platform::Windows));
+
+        let json = serde_json::to_value(&reqs).expect("Should serialize requirements");
+        assert_eq!(json["supported_platforms"], serde_json::json!(["macos", "linux"]));
+
+        let legacy: SystemRequirements = serde_json::from_str(
+            r#"{"min_cpu_cores":2,"min_memory_kb":1024,"supported_platforms":["Darwin"," linux "]}"#,
+        ).expect("Should deserialize legacy platform names");
+        assert!(legacy.supports(&Platform::MacOs), "darwin should normalize to macos");
+        assert!(legacy.supports(&Platform::Linux));
+        assert_eq!(legacy.supported_platforms, reqs.supported_platforms);
+    }
+
+    #[test]
+    fn test_disk_io_rates() {
+        let before = hardware::parse_diskstats(
+            "   7       0 loop0 10 0 80 0 0 0 0 0 0 0 0 0 0 0 0 0 0\n \
+             259       0 nvme0n1 1000 0 2000 50 500 0 4000 90 0 100 140 0 0 0 0 0 0\n \
+               8       0 sda 10 0 100 5 10 0 50 5 0 10 10\n",
+        );
+        assert_eq!(before, vec![
+            ("nvme0n1".to_string(), 2000 * 512, 4000 * 512),
+            ("sda".to_string(), 100 * 512, 50 * 512),
+        ], "Loop devices should be skipped and sectors converted to bytes");
+
+        // sda's read counter went backwards, as after a device reset
+        let after = vec![
+            ("nvme0n1".to_string(), 4000 * 512, 4000 * 512),
+            ("sda".to_string(), 0, 150 * 512),
+        ];
+        let stats = hardware::disk_io_between(&before, &after, Duration::from_secs(2));
+        assert_eq!(stats.disks[0].read_bytes_per_sec, 1000.0 * 512.0);
+        assert_eq!(stats.disks[0].write_bytes_per_sec, 0.0);
+        assert_eq!(stats.disks[1].read_bytes_per_sec, 0.0, "Reset counters should not produce a rate");
+        assert_eq!(stats.disks[1].write_bytes_per_sec, 50.0 * 512.0);
+
+        let sampled = hardware::sample_disk_io(Duration::from_millis(50));
+        for disk in sampled.disks {
+            assert!(disk.read_bytes_per_sec >= 0.0 && disk.write_bytes_per_sec >= 0.0, "Negative rate for {}", disk.name);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[tokio::test]
+    async fn test_blocking_call_on_runtime_warns() {
+        assert!(hardware::warn_if_on_async_runtime("test"), "Should warn on a runtime thread");
+
+        let warned = hardware::run_blocking(|| hardware::warn_if_on_async_runtime("test"))
+            .await
+            .expect("Blocking task should complete");
+        assert!(!warned, "run_blocking work should not warn");
+
+        let warned = thread::spawn(|| hardware::warn_if_on_async_runtime("test"))
+            .join()
+            .expect("Thread should complete");
+        assert!(!warned, "Plain threads should not warn");
+
+        let panicked = std::panic::catch_unwind(|| {
+            let _guard = hardware::BlockingTaskGuard::enter();
+            panic!("reader failed");
+        });
+        assert!(panicked.is_err());
+        assert!(
+            hardware::warn_if_on_async_runtime("test"),
+            "A panic inside blocking work should not leave the thread marked"
+        );
+    }
+
+    #[test]
+    fn test_export_diagnostics() {
+        let json = hardware::export_diagnostics().expect("Should export diagnostics");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Diagnostics should be valid JSON");
+        for key in ["timestamp", "appVersion", "hardware", "disks", "networkInterfaces"] {
+            assert!(value.get(key).is_some(), "Missing top-level key {}", key);
+        }
+        assert!(value["hardware"]["cpuCount"].as_u64().unwrap_or(0) > 0);
+        assert!(value["timestamp"].as_str().unwrap_or("").ends_with('Z'), "Timestamp should be UTC");
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn test_detect_apple_runtime_on_macos() {
+        let runtime = hardware::detect_apple_runtime();
+        if cfg!(target_arch = "aarch64") {
+            assert_eq!(runtime, hardware::AppleRuntime::Native, "arm64 builds can't be translated");
+        } else {
+            assert_ne!(runtime, hardware::AppleRuntime::Native, "x86_64 builds are never native on Apple Silicon");
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_detect_apple_runtime_elsewhere() {
+        assert_eq!(hardware::detect_apple_runtime(), hardware::AppleRuntime::NotApple);
+    }
+
+    #[test]
+    fn test_hardware_info_diff() {
+        let before = HardwareInfo {
+            cpu_count: 8,
+            physical_cores: Some(4),
+            memory_total: 16384,
+            memory_used: 4096,
+            available_memory: 12288,
+            kernel_version: Some("6.1.0".to_string()),
+            ..sample_info()
+        };
+        assert!(before.diff(&before.clone()).is_empty(), "Identical snapshots should have no changes");
+
+        let after = HardwareInfo {
+            memory_used: 8192,
+            available_memory: 8192,
+            kernel_version: None,
+            collected_at: "2024-01-01T00:05:00Z".to_string(),
+            ..before.clone()
+        };
+        let changes = before.diff(&after);
+        assert_eq!(changes, vec![
+            hardware::FieldChange {
+                field: "availableMemory".to_string(),
+                from: "12288".to_string(),
+                to: "8192".to_string(),
+            },
+            hardware::FieldChange {
+                field: "kernelVersion".to_string(),
+                from: "6.1.0".to_string(),
+                to: "none".to_string(),
+            },
+            hardware::FieldChange {
+                field: "memoryUsed".to_string(),
+                from: "4096".to_string(),
+                to: "8192".to_string(),
+            },
+        ], "Changes should be sorted by field name");
+    }
+
+    #[test]
+    fn test_memory_snapshot_is_faster_than_full_reading() {
+        let start = Instant::now();
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        let full = start.elapsed();
+
+        hardware::get_memory_snapshot().expect("Should get first memory snapshot");
+        let start = Instant::now();
+        let snapshot = hardware::get_memory_snapshot().expect("Should get memory snapshot");
+        let fast = start.elapsed();
+
+        assert_eq!(snapshot.memory_total, info.memory_total, "Both paths should see the same total memory");
+        assert!(snapshot.memory_used <= snapshot.memory_total);
+        assert!(fast * 5 <= full, "Snapshot took {:?}, full reading {:?}", fast, full);
+    }
+
+    #[test]
+    fn test_hardware_monitor_refresh() {
+        let (monitor, _) = counting_monitor();
+        let cached = monitor.get_cached(Duration::from_secs(60)).expect("Should get hardware info");
+        assert_eq!(cached.memory_used, 1);
+
+        let refreshed = monitor.refresh().expect("Should refresh hardware info");
+        assert_eq!(refreshed.memory_used, 2, "Refresh should bypass a fresh cache");
+
+        let after = monitor.get_cached(Duration::from_secs(60)).expect("Should get cached info");
+        assert_eq!(after, refreshed, "Refreshed reading should replace the cache");
+
+        monitor.invalidate();
+        let reread = monitor.get_cached(Duration::from_secs(60)).expect("Should get hardware info");
+        assert_eq!(reread.memory_used, 3, "Invalidated cache should be read again");
+    }
+
+    #[test]
+    fn test_requirements_builder_units() {
+        let built = SystemRequirements::builder()
+            .min_memory_gib(4)
+            .min_cpu_cores(2)
+            .build();
+        let default = SystemRequirements::default();
+        assert_eq!(built.min_memory(), default.min_memory(), "4 GiB should match the default");
+        assert_eq!(built.min_memory(), Kib(4 * 1024 * 1024));
+        assert_eq!(built.min_cpu_cores, default.min_cpu_cores);
+        assert_eq!(built.supported_platforms, default.supported_platforms);
+
+        let half = SystemRequirements::builder().min_memory_mib(512).build();
+        assert_eq!(half.min_memory(), Kib(512 * 1024));
+
+        // The private field keeps its KB name on the wire
+        let json = serde_json::to_value(&built).expect("Should serialize requirements");
+        assert_eq!(json["min_memory_kb"], serde_json::json!(4 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_cpu_frequency() {
+        let info = hardware::get_hardware_info().expect("Should get hardware info");
+        if let Some(mhz) = info.cpu_frequency_mhz {
+            assert!(mhz > 100, "Implausible CPU frequency: {} MHz", mhz);
+        }
+
+        let sysfs = temp_dir("cpufreq");
+        fs::create_dir_all(sysfs.join("cpu0").join("cpufreq")).unwrap();
+        fs::write(sysfs.join("cpu0").join("cpufreq").join("cpuinfo_max_freq"), "4700000\n").unwrap();
+        assert_eq!(hardware::read_cpu_max_frequency_mhz_from(&sysfs), Some(4700));
+        assert_eq!(hardware::read_cpu_max_frequency_mhz_from(&temp_dir("cpufreq-missing")), None);
+    }
+
+    #[test]
+    fn test_compatibility_with_static_provider() {
+        let minimal = HardwareInfo {
+            cpu_count: 2,
+            physical_cores: Some(2),
+            memory_total: Bytes::from(Mib(4 * 1024)).0,
+            memory_used: 0,
+            available_memory: 0,
+            platform: Platform::Windows,
+            ..sample_info()
+        };
+        let provider = hardware::StaticProvider::new(minimal.clone());
+        let info = hardware::check_system_compatibility_with(&provider).expect("Exact minimums should pass");
+        assert_eq!(info, minimal, "The provider's reading should be returned");
+
+        let cases = [
+            (HardwareInfo { cpu_count: 1, ..minimal.clone() }, "Insufficient CPU cores"),
+            (HardwareInfo { memory_limit: Some(1024), ..minimal.clone() }, "Insufficient memory"),
+            (HardwareInfo { platform: Platform::FreeBsd, ..minimal.clone() }, "Unsupported platform"),
+        ];
+        for (info, expected) in cases {
+            match hardware::check_system_compatibility_with(&hardware::StaticProvider::new(info)) {
+                Err(HardwareError::CompatibilityError(msg)) => assert!(msg.contains(expected), "{} should mention {}", msg, expected),
+                other => panic!("Expected a compatibility error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cgroup_cpu_limit() {
+        let v2 = temp_dir("cgroup-cpu-v2");
+        fs::write(v2.join("cpu.max"), "150000 100000\n").unwrap();
+        assert_eq!(hardware::read_cgroup_cpu_limit_from(&v2), Some(1.5));
+
+        let v2_unlimited = temp_dir("cgroup-cpu-v2-unlimited");
+        fs::write(v2_unlimited.join("cpu.max"), "max 100000\n").unwrap();
+        assert_eq!(hardware::read_cgroup_cpu_limit_from(&v2_unlimited), None, "\"max\" means no quota");
+
+        let v1 = temp_dir("cgroup-cpu-v1");
+        fs::create_dir_all(v1.join("cpu")).unwrap();
+        fs::write(v1.join("cpu").join("cpu.cfs_quota_us"), "200000\n").unwrap();
+        fs::write(v1.join("cpu").join("cpu.cfs_period_us"), "100000\n").unwrap();
+        assert_eq!(hardware::read_cgroup_cpu_limit_from(&v1), Some(2.0));
+
+        let v1_unlimited = temp_dir("cgroup-cpu-v1-unlimited");
+        fs::create_dir_all(v1_unlimited.join("cpu")).unwrap();
+        fs::write(v1_unlimited.join("cpu").join("cpu.cfs_quota_us"), "-1\n").unwrap();
+        fs::write(v1_unlimited.join("cpu").join("cpu.cfs_period_us"), "100000\n").unwrap();
+        assert_eq!(hardware::read_cgroup_cpu_limit_from(&v1_unlimited), None, "-1 means no quota");
+
+        assert_eq!(hardware::read_cgroup_cpu_limit_from(&temp_dir("cgroup-cpu-missing")), None);
+    }
+
+    #[test]
+    fn test_parse_dmidecode_memory() {
+        let output = "\
+# dmidecode 3.3
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+\tTotal Width: 64 bits
+\tSize: 16 GB
+\tLocator: DIMM_A1
+\tBank Locator: BANK 0
+\tType: DDR4
+\tSpeed: 3200 MT/s
+\tManufacturer: Samsung
+\tConfigured Memory Speed: 2933 MT/s
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tSize: No Module Installed
+\tLocator: DIMM_A2
+\tSpeed: Unknown
+\tManufacturer: Not Specified
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+\tSize: 8192 MB
+\tLocator: DIMM_B1
+\tSpeed: Unknown
+\tManufacturer: Unknown
+";
+        let modules = hardware::parse_dmidecode_memory(output);
+        assert_eq!(modules, vec![
+            hardware::MemoryModule {
+                size_bytes: 16 * 1024 * 1024 * 1024,
+                speed_mhz: Some(2933),
+                manufacturer: Some("Samsung".to_string()),
+                slot: Some("DIMM_A1".to_string()),
+            },
+            hardware::MemoryModule {
+                size_bytes: 8 * 1024 * 1024 * 1024,
+                speed_mhz: None,
+                manufacturer: None,
+                slot: Some("DIMM_B1".to_string()),
+            },
+        ], "Empty slots and placeholder values should be skipped");
+        assert!(hardware::parse_dmidecode_memory("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_system_profiler_and_wmi_memory() {
+        let intel = r#"{"SPMemoryDataType":[{"_items":[
+            {"_name":"BANK 0/ChannelA-DIMM0","dimm_size":"8 GB","dimm_speed":"2667 MHz","dimm_manufacturer":"0x802C"},
+            {"_name":"BANK 2/ChannelB-DIMM0","dimm_size":"8 GB","dimm_speed":"2667 MHz","dimm_manufacturer":"0x802C"}
+        ],"global_ecc_state":"ecc_disabled"}]}"#;
+        let modules = hardware::parse_system_profiler_memory(intel);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].slot.as_deref(), Some("BANK 0/ChannelA-DIMM0"));
+        assert_eq!(modules[0].speed_mhz, Some(2667));
+
+        let apple_silicon = r#"{"SPMemoryDataType":[{"SPMemoryDataType":"16 GB","dimm_manufacturer":"Hynix","dimm_type":"LPDDR5"}]}"#;
+        assert_eq!(hardware::parse_system_profiler_memory(apple_silicon), vec![hardware::MemoryModule {
+            size_bytes: 16 * 1024 * 1024 * 1024,
+            speed_mhz: None,
+            manufacturer: Some("Hynix".to_string()),
+            slot: None,
+        }]);
+
+        let single = r#"{"Capacity":17179869184,"Speed":3200,"Manufacturer":"Micron","DeviceLocator":"DIMM 0"}"#;
+        let modules = hardware::parse_wmi_memory(single);
+        assert_eq!(modules.len(), 1, "A bare object is a single module");
+        assert_eq!(modules[0].size_bytes, 17179869184);
+        assert_eq!(modules[0].speed_mhz, Some(3200));
+
+        let several = r#"[{"Capacity":8589934592,"Speed":0,"Manufacturer":"Unknown","DeviceLocator":"ChannelA"},
+                          {"Capacity":8589934592,"Speed":2400,"Manufacturer":"Kingston","DeviceLocator":"ChannelB"}]"#;
+        let modules = hardware::parse_wmi_memory(several);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].speed_mhz, None, "Zero speed means unknown");
+        assert_eq!(modules[0].manufacturer, None);
+        assert!(hardware::parse_wmi_memory("").is_empty());
+    }
+
+    #[test]
+    fn test_collected_at_increases() {
+        let first = hardware::get_hardware_info().expect("Should get first hardware info");
+        let second = hardware::get_hardware_info().expect("Should get second hardware info");
+
+        let parse = |info: &HardwareInfo| chrono::DateTime::parse_from_rfc3339(&info.collected_at)
+            .expect("collected_at should be RFC 3339");
+        assert!(parse(&second) > parse(&first), "{} should be after {}", second.collected_at, first.collected_at);
+
+        let json = serde_json::to_value(&first).expect("Should serialize hardware info");
+        assert_eq!(json["collectedAt"], serde_json::json!(first.collected_at));
+        let restored: HardwareInfo = serde_json::from_value(json).expect("Should deserialize hardware info");
+        assert_eq!(restored.collected_at, first.collected_at);
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_model_name() {
+        let cpuinfo = "\
+processor\t: 0
+vendor_id\t: GenuineIntel
+cpu family\t: 6
+model\t\t: 85
+model name\t: Intel(R) Xeon(R) Platinum 8259CL CPU @ 2.50GHz
+stepping\t: 7
+
+processor\t: 1
+vendor_id\t: GenuineIntel
+model name\t: Intel(R) Xeon(R) Platinum 8259CL CPU @ 2.50GHz
+";
+        assert_eq!(
+            hardware::parse_cpuinfo_model_name(cpuinfo).as_deref(),
+            Some("Intel(R) Xeon(R) Platinum 8259CL CPU @ 2.50GHz")
+        );
+        assert_eq!(hardware::parse_cpuinfo_model_name("processor\t: 0\nmodel name\t: \n"), None, "Blank names should be ignored");
+        assert_eq!(hardware::parse_cpuinfo_model_name("processor\t: 0\nBogoMIPS\t: 48.00\n"), None);
     }
 }
 
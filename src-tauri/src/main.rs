@@ -1,58 +1,133 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use homewiseai::hardware::{self, HardwareInfo};
-use serde_json;
-use std::fs::OpenOptions;
-use std::io::Write;
-
-fn log_to_file(message: &str) {
-    if let Ok(mut file) = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("app.log")
-    {
-        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-        if let Err(e) = writeln!(file, "[{}] {}", timestamp, message) {
-            eprintln!("Failed to write to log file: {}", e);
+use homewiseai::hardware::{self, HardwareInfo, HardwareMonitor, HealthReport};
+use homewiseai::logging;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+/// Age up to which `get_hardware_info` serves the monitor's cached reading
+const HARDWARE_INFO_MAX_AGE: Duration = Duration::from_secs(1);
+
+#[tauri::command]
+async fn get_hardware_info(monitor: State<'_, Arc<HardwareMonitor>>) -> Result<HardwareInfo, String> {
+    let monitor = Arc::clone(&monitor);
+    let result = hardware::run_blocking(move || monitor.get_cached(HARDWARE_INFO_MAX_AGE))
+        .await
+        .and_then(|result| result);
+    tracing::info_span!("get_hardware_info").in_scope(|| match result {
+        Ok(info) => {
+            tracing::info!(
+                cpu_count = info.cpu_count,
+                cpu_brand = %info.cpu_brand,
+                memory_total = info.memory_total,
+                memory_used = info.memory_used,
+                platform = %info.platform,
+                "Retrieved hardware info"
+            );
+            Ok(info)
         }
-    }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get hardware info");
+            Err(e.to_string())
+        }
+    })
+}
+
+#[tauri::command]
+async fn system_health(app: AppHandle) -> Result<HealthReport, String> {
+    let data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve the app data directory".to_string())?;
+    let report = hardware::run_blocking(move || hardware::system_health(&data_dir))
+        .await
+        .map_err(|e| e.to_string())?;
+    tracing::info!(status = ?report.status, "Ran system health checks");
+    Ok(report)
 }
 
 #[tauri::command]
-async fn get_hardware_info() -> Result<HardwareInfo, String> {
-    log_to_file("Handling get_hardware_info command");
-    let result = hardware::get_hardware_info()
+async fn export_diagnostics() -> Result<String, String> {
+    hardware::run_blocking(hardware::export_diagnostics)
+        .await
+        .and_then(|result| result)
         .map_err(|e| {
-            let error_msg = format!("Error getting hardware info: {}", e);
-            log_to_file(&error_msg);
+            tracing::error!(error = %e, "Failed to export diagnostics");
             e.to_string()
-        });
-    
-    match &result {
-        Ok(info) => {
-            log_to_file(&format!("CPU Count: {}", info.cpu_count));
-            log_to_file(&format!("CPU Brand: {}", info.cpu_brand));
-            log_to_file(&format!("Memory Total: {} KB", info.memory_total));
-            log_to_file(&format!("Memory Used: {} KB", info.memory_used));
-            log_to_file(&format!("Platform: {}", info.platform));
-            
-            match serde_json::to_string_pretty(info) {
-                Ok(json) => log_to_file(&format!("Hardware info as JSON:\n{}", json)),
-                Err(e) => log_to_file(&format!("Error serializing hardware info: {}", e)),
+        })
+}
+
+#[tauri::command]
+async fn refresh_hardware(monitor: State<'_, Arc<HardwareMonitor>>) -> Result<HardwareInfo, String> {
+    let monitor = Arc::clone(&monitor);
+    hardware::run_blocking(move || monitor.refresh())
+        .await
+        .and_then(|result| result)
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to refresh hardware info");
+            e.to_string()
+        })
+}
+
+#[tauri::command]
+fn prewarm_hardware() {
+    hardware::prewarm();
+}
+
+#[tauri::command]
+fn start_hardware_monitor(
+    app: AppHandle,
+    monitor: State<'_, Arc<HardwareMonitor>>,
+    interval_ms: u64,
+) -> Result<(), String> {
+    if interval_ms == 0 {
+        return Err("Monitor interval must be greater than zero".to_string());
+    }
+    let stop = hardware::claim_monitor()
+        .ok_or_else(|| "Hardware monitor is already running".to_string())?;
+
+    tracing::info!(interval_ms, "Starting hardware monitor");
+    tauri::async_runtime::spawn(hardware::run_monitor_loop(
+        Arc::clone(&monitor),
+        stop,
+        Duration::from_millis(interval_ms),
+        move |result| match result {
+            Ok(info) => {
+                if let Err(e) = app.emit_all("hardware-update", &info) {
+                    tracing::warn!(error = %e, "Failed to emit hardware update");
+                }
             }
-        }
-        Err(e) => log_to_file(&format!("Failed to get hardware info: {}", e)),
+            Err(e) => tracing::warn!(error = %e, "Hardware monitor failed to read hardware info"),
+        },
+    ));
+    Ok(())
+}
+
+#[tauri::command]
+fn stop_hardware_monitor() {
+    if hardware::stop_monitor() {
+        tracing::info!("Stopped hardware monitor");
     }
-    
-    result
 }
 
 fn main() {
-    log_to_file("Starting application");
+    logging::init_logging();
+    tracing::info!("Starting application");
+    hardware::prewarm();
     let context = tauri::generate_context!();
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![get_hardware_info])
+        .manage(Arc::new(HardwareMonitor::new()))
+        .invoke_handler(tauri::generate_handler![
+            get_hardware_info,
+            system_health,
+            export_diagnostics,
+            refresh_hardware,
+            prewarm_hardware,
+            start_hardware_monitor,
+            stop_hardware_monitor
+        ])
         .run(context)
         .expect("error while running tauri application");
 }